@@ -1,7 +1,9 @@
 use log::trace;
 use std::fmt::Display;
 use uom::fmt::DisplayStyle;
-use uom::si::f64::{Area, Mass, TemperatureInterval, Time, Volume};
+use uom::si::energy::joule;
+use uom::si::f64::{Area, Energy, Mass, Radioactivity, Ratio, TemperatureInterval, Time, Volume};
+use uom::si::heat_capacity::joule_per_kelvin;
 use uom::si::mass::kilogram;
 use uom::si::pressure::bar;
 use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
@@ -9,35 +11,61 @@ use uom::si::volume::cubic_meter;
 use uom::{num_traits::Zero, si::f64::Pressure};
 
 use crate::error::Error;
-use crate::substance::water::{self, gas_liquid_heat_transfer_coefficient, Water};
+use crate::substance::non_condensable_gas::GasMixture;
+use crate::substance::water::isotopes::Isotope;
+use crate::substance::water::Water;
+use crate::substance::Substance;
 
+#[cfg(test)]
+mod tests;
+
+/// A container holding a substance in liquid and gaseous form.
 #[derive(Debug, Clone)]
-pub struct WaterContainer {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Container<S: Substance> {
     /// The total volume of the container.
+    #[cfg_attr(feature = "serde", serde(rename = "volume_m3"))]
     volume: Volume,
 
-    /// The surface area between steam and water in the container.
-    /// This controls how fast the temperature between water and steam gets equalised.
+    /// The surface area between gas and liquid in the container.
+    /// This controls how fast the temperature between liquid and gas gets equalised.
+    #[cfg_attr(feature = "serde", serde(rename = "surface_area_m2"))]
     surface_area: Area,
 
-    /// The water in the container.
-    water: Water,
-    /// The steam in the container.
-    steam: Water,
+    /// The liquid in the container.
+    liquid: S,
+    /// The gas in the container.
+    vapour: S,
+    /// The non-condensable gases in the container, sharing the volume with the gas.
+    gases: GasMixture,
 }
 
+pub type WaterContainer = Container<Water>;
+
 pub struct PhaseEquillibrium {
     should_condensate: bool,
     should_evaporate: bool,
 }
 
-impl WaterContainer {
-    pub fn new(volume: Volume, surface_area: Area, water: Water, steam: Water) -> Self {
+impl<S: Substance> Container<S> {
+    /// Create a container with the given liquid and gas.
+    /// The gas is turned into gas at its temperature, see [`Substance::into_vapour`].
+    /// Hence, e.g. [`Water::new`] and [`Substance::zero`] may be used for the gas, like before water tracked its phase.
+    pub fn new(volume: Volume, surface_area: Area, liquid: S, vapour: S) -> Self {
         Self {
             volume,
             surface_area,
-            water,
-            steam,
+            liquid,
+            vapour: vapour.into_vapour(),
+            gases: GasMixture::zero(),
+        }
+    }
+
+    /// Add non-condensable gases to the container.
+    pub fn with_gases(self, gases: GasMixture) -> Self {
+        Self {
+            gases: self.gases + gases,
+            ..self
         }
     }
 
@@ -45,114 +73,237 @@ impl WaterContainer {
         self.volume
     }
 
-    /// The surface area between water and steam.
+    /// The surface area between liquid and gas.
     /// This controls how fast temperature is convected.
     pub fn surface_area(&self) -> Area {
         self.surface_area
     }
 
+    pub fn liquid(&self) -> &S {
+        &self.liquid
+    }
+
+    pub fn vapour(&self) -> &S {
+        &self.vapour
+    }
+
+    pub fn gases(&self) -> &GasMixture {
+        &self.gases
+    }
+
+    /// Whether the liquid is above its critical temperature.
+    /// Then it is a single fluid phase that fills the whole container, and there is no gas.
+    pub fn is_supercritical(&self) -> bool {
+        !self.liquid.mass().is_zero()
+            && self.liquid.temperature() >= self.liquid.critical_temperature()
+    }
+
+    /// Whether the liquid fills the whole container, leaving no space for gas.
+    /// Then the liquid is compressed, and the pressure is given by its bulk modulus.
+    pub fn is_full(&self) -> bool {
+        !self.is_supercritical() && self.liquid.volume() >= self.volume
+    }
+
+    pub fn liquid_volume(&self) -> Volume {
+        if self.is_supercritical() {
+            self.volume
+        } else if self.is_full() {
+            self.compressed_liquid_volume()
+        } else {
+            self.liquid.volume()
+        }
+    }
+
+    pub fn vapour_volume(&self) -> Volume {
+        self.volume - self.liquid_volume()
+    }
+
+    #[deprecated(note = "renamed to `liquid_volume`")]
     pub fn water_volume(&self) -> Volume {
-        self.water.volume()
+        self.liquid_volume()
     }
 
+    #[deprecated(note = "renamed to `vapour_volume`")]
     pub fn steam_volume(&self) -> Volume {
-        self.volume - self.water_volume()
+        self.vapour_volume()
     }
 
+    /// The total pressure in the container.
+    /// By Dalton's law, this is the sum of the partial pressures of the gas and the non-condensable gases.
+    /// If the container is full of liquid, this is the pressure of the compressed liquid.
     pub fn pressure(&self) -> Pressure {
-        self.steam.pressure(self.steam_volume())
+        if self.is_full() {
+            self.liquid.liquid_pressure(self.liquid_volume())
+        } else {
+            self.vapour_pressure() + self.gases_pressure()
+        }
+    }
+
+    /// The partial pressure of the gas of the substance.
+    /// If the substance is supercritical, this is the pressure of the fluid.
+    pub fn vapour_pressure(&self) -> Pressure {
+        if self.is_supercritical() {
+            self.liquid.pressure(self.volume)
+        } else if self.is_full() {
+            self.pressure() - self.gases_pressure()
+        } else {
+            self.vapour.pressure(self.vapour_volume())
+        }
+    }
+
+    /// Vent some mass of the non-condensable gases, taking each gas by its share of the mass.
+    /// Returns an error if the mass is negative or more than there is.
+    pub fn try_remove_gases(&mut self, mass: Mass) -> Result<GasMixture, Error> {
+        self.gases.try_remove(mass)
+    }
+
+    /// The partial pressure of the non-condensable gases.
+    fn gases_pressure(&self) -> Pressure {
+        if self.gases.total_mass().is_zero() {
+            Pressure::zero()
+        } else if self.is_supercritical() {
+            self.gases.pressure(self.volume)
+        } else {
+            self.gases.pressure(self.vapour_volume())
+        }
+    }
+
+    /// The volume of the liquid if it fills the whole container.
+    /// The liquid is compressed until its pressure matches the pressure of the non-condensable gases squeezed into the remaining volume.
+    fn compressed_liquid_volume(&self) -> Volume {
+        if self.gases.total_mass().is_zero() {
+            return self.volume;
+        }
+
+        let mut lower = Volume::zero();
+        let mut upper = self.volume;
+        for _ in 0..100 {
+            let middle = (lower + upper) / 2.0;
+            if self.liquid.liquid_pressure(middle) > self.gases.pressure(self.volume - middle) {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+        (lower + upper) / 2.0
     }
 
-    /// Evaporate a given mass of water into steam.
-    fn evaporate(&mut self, mass: Mass) -> Result<(), Error> {
-        assert!(mass >= Mass::zero() && mass <= self.water.mass());
-        let additional_steam = self.water.evaporate(mass)?;
-        self.steam += additional_steam;
+    /// Evaporate a given mass of liquid into gas.
+    fn try_evaporate(&mut self, mass: Mass) -> Result<(), Error> {
+        let mut liquid = self.liquid;
+        let additional_vapour = liquid.evaporate(mass)?;
+        self.vapour = self.vapour.checked_add(additional_vapour)?;
+        self.liquid = liquid;
         Ok(())
     }
 
-    /// Condensate a given mass of steam into water.
-    fn condensate(&mut self, mass: Mass) {
-        assert!(mass >= Mass::zero() && mass <= self.steam.mass());
-        let additional_water = self.steam.condensate(mass);
-        self.water += additional_water;
+    /// Condensate a given mass of gas into liquid.
+    fn try_condensate(&mut self, mass: Mass) -> Result<(), Error> {
+        let mut vapour = self.vapour;
+        let additional_liquid = vapour.try_condensate(mass)?;
+        self.liquid = self.liquid.checked_add(additional_liquid)?;
+        self.vapour = vapour;
+        Ok(())
     }
 
     pub fn phase_equillibrium(&self) -> PhaseEquillibrium {
-        if self.steam.mass().is_zero() && self.water.mass().is_zero() {
+        if (self.vapour.mass().is_zero() && self.liquid.mass().is_zero()) || self.is_supercritical()
+        {
             PhaseEquillibrium {
                 should_condensate: false,
                 should_evaporate: false,
             }
         } else {
-            let steam_volume = self.steam_volume();
-
-            if steam_volume.is_sign_negative() {
-                // We assume water to be incompressible, so here steam would be at infinite pressure.
-                // Hence, it should condensate all steam, and evaporate nothing.
+            if self.is_full() {
+                // The liquid leaves no space for gas.
+                // Hence, it should condensate all gas, and evaporate nothing.
                 PhaseEquillibrium {
-                    should_condensate: true,
+                    should_condensate: !self.vapour.mass().is_zero(),
                     should_evaporate: false,
                 }
             } else {
-                let pressure = self.pressure();
-                let water_saturation_pressure = self.water.saturation_pressure();
-                let steam_saturation_pressure = self.steam.saturation_pressure();
+                let pressure = self.vapour_pressure();
+                let liquid_saturation_pressure = self.liquid.saturation_pressure();
+                let vapour_saturation_pressure = self.vapour.saturation_pressure();
                 PhaseEquillibrium {
-                    should_condensate: pressure > steam_saturation_pressure,
-                    should_evaporate: pressure < water_saturation_pressure,
+                    should_condensate: pressure > vapour_saturation_pressure,
+                    should_evaporate: pressure < liquid_saturation_pressure,
                 }
             }
         }
     }
 
-    /// Evaporate and condensate water to reach the saturation pressure.
+    /// Evaporate and condensate the substance until its partial pressure reaches the saturation pressure.
+    /// Non-condensable gases do not take part in this.
     /// Note that both processes happen simultaneously.
-    /// Specifically, if the water is hot but the steam is cold, this will result in both processes happening at the same time.
-    /// And, if the steam is hot but the water is cold, nothing will happen.
+    /// Specifically, if the liquid is hot but the gas is cold, this will result in both processes happening at the same time.
+    /// And, if the gas is hot but the liquid is cold, nothing will happen.
+    ///
+    /// If the liquid fills the whole container, then all gas will condensate into it.
+    /// The liquid is then compressed, see [`Container::pressure`].
     ///
-    /// If for some reason the volume left by the water is negative, then all steam will condensate.
-    /// This is because we assume water to be incompressible.
-    pub fn evaporate_condensate(&mut self) {
-        let steam_volume = self.steam_volume();
-
-        if steam_volume <= Volume::zero() {
-            // We assume water to be incompressible, so here steam would be at infinite pressure.
-            // Hence, we can condensate it completely.
-            self.water += self.steam;
-            self.steam = Water::zero();
+    /// If the liquid is above its critical temperature, all gas is merged into it, as there is only a single fluid phase.
+    ///
+    /// If this fails, the container is left unchanged.
+    pub fn evaporate_condensate(&mut self) -> Result<(), Error> {
+        let vapour_volume = self.vapour_volume();
+
+        if self.is_supercritical() {
+            // Above the critical point, there is no distinction between liquid and gas, so everything becomes a single fluid.
+            if !self.vapour.mass().is_zero() {
+                self.try_condensate(self.vapour.mass())?;
+            }
+        } else if self.is_full() {
+            // The liquid leaves no space for gas, so we can condensate it completely.
+            if !self.vapour.mass().is_zero() {
+                self.try_condensate(self.vapour.mass())?;
+            }
         } else {
-            // Evaporate water and condensate steam.
+            // Evaporate liquid and condensate gas.
             // First, compute an upper bound of the mass that can be evaporated and condensated to step towards the equillibrium.
-            let pressure = self.pressure();
-            let water_saturation_pressure = self.water.saturation_pressure();
-            let steam_saturation_pressure = self.steam.saturation_pressure();
-            let water_evaporation_potential = (water_saturation_pressure - pressure) * steam_volume;
-            let steam_condensation_potential =
-                (pressure - steam_saturation_pressure) * steam_volume;
-            let water_evaporation_mass = water_evaporation_potential
-                / (water::SPECIAL_IDEAL_GAS_CONSTANT * self.water.temperature());
-            let steam_condensation_mass = steam_condensation_potential
-                / (water::SPECIAL_IDEAL_GAS_CONSTANT * self.steam.temperature());
-            let water_evaporation_mass = water_evaporation_mass
+            let pressure = self.vapour_pressure();
+            let liquid_saturation_pressure = self.liquid.saturation_pressure();
+            let vapour_saturation_pressure = self.vapour.saturation_pressure();
+            let liquid_evaporation_potential =
+                (liquid_saturation_pressure - pressure) * vapour_volume;
+            let vapour_condensation_potential =
+                (pressure - vapour_saturation_pressure) * vapour_volume;
+            // The compressibility factor falls as the gas gets denser, so take it at the higher of both pressures.
+            let liquid_evaporation_mass = liquid_evaporation_potential
+                / (self.liquid.gas_constant()
+                    * self
+                        .liquid
+                        .compressibility_factor(pressure.max(liquid_saturation_pressure))
+                    * self.liquid.temperature());
+            let vapour_condensation_mass = vapour_condensation_potential
+                / (self.vapour.gas_constant()
+                    * self
+                        .vapour
+                        .compressibility_factor(pressure.max(vapour_saturation_pressure))
+                    * self.vapour.temperature());
+            let liquid_evaporation_mass = liquid_evaporation_mass
                 .max(Mass::zero())
-                .min(self.water.mass());
-            let steam_condensation_mass = steam_condensation_mass
+                .min(self.liquid.mass());
+            let vapour_condensation_mass = vapour_condensation_mass
                 .max(Mass::zero())
-                .min(self.steam.mass());
+                .min(self.vapour.mass());
 
             // Then, do binary search for the actual amounts.
             let mut left = Mass::zero();
-            let mut right = water_evaporation_mass;
+            let mut right = liquid_evaporation_mass;
             let mut iteration = 0;
-            let target_accuracy = self.water.mass().min(self.steam.mass()) * 1e-2;
+            let mut target_accuracy = self.liquid.mass().min(self.vapour.mass()) * 1e-2;
+            if target_accuracy.is_zero() {
+                // One phase is empty, so take the accuracy from the mass that may move instead.
+                target_accuracy = liquid_evaporation_mass.max(vapour_condensation_mass) * 1e-2;
+            }
             while (right - left) > target_accuracy {
                 assert!(right > left);
                 iteration += 1;
 
                 let middle = (right + left) / 2.0;
                 let mut test_container = self.clone();
-                if test_container.evaporate(middle).is_err() {
+                if test_container.try_evaporate(middle).is_err() {
                     right = middle;
                 } else {
                     let phase_equillibrium = test_container.phase_equillibrium();
@@ -163,11 +314,12 @@ impl WaterContainer {
                     }
                 }
             }
-            let water_evaporation_mass = (right + left) / 2.0;
+            let liquid_evaporation_mass = (right + left) / 2.0;
+            let liquid_evaporation_lower_bound = left;
             trace!("Took {iteration} iterations to compute evaporation");
 
             let mut left = Mass::zero();
-            let mut right = steam_condensation_mass;
+            let mut right = vapour_condensation_mass;
             let mut iteration = 0;
 
             while (right - left) > target_accuracy {
@@ -176,60 +328,167 @@ impl WaterContainer {
 
                 let middle = (right + left) / 2.0;
                 let mut test_container = self.clone();
-                test_container.condensate(middle);
-                let phase_equillibrium = test_container.phase_equillibrium();
-                if phase_equillibrium.should_condensate {
-                    left = middle;
-                } else {
+                if test_container.try_condensate(middle).is_err() {
                     right = middle;
+                } else {
+                    let phase_equillibrium = test_container.phase_equillibrium();
+                    if phase_equillibrium.should_condensate {
+                        left = middle;
+                    } else {
+                        right = middle;
+                    }
                 }
             }
-            let steam_condensation_mass = (right + left) / 2.0;
+            let vapour_condensation_mass = (right + left) / 2.0;
             trace!("Took {iteration} iterations to compute evaporation");
 
-            self.water.simultaneous_mass_exchange(
-                &mut self.steam,
-                water_evaporation_mass,
-                steam_condensation_mass,
-            );
+            // Both processes happen simultaneously, so first take the mass from both phases, and then add it to the other.
+            let mut liquid = self.liquid;
+            let mut vapour = self.vapour;
+            let evaporated = liquid
+                .evaporate(liquid_evaporation_mass)
+                .or_else(|_| liquid.evaporate(liquid_evaporation_lower_bound))?;
+            let condensate = vapour.try_condensate(vapour_condensation_mass)?;
+            let liquid = liquid.checked_add(condensate)?;
+            let vapour = vapour.checked_add(evaporated)?;
+            self.liquid = liquid;
+            self.vapour = vapour;
         }
+        Ok(())
     }
 
-    /// Transfer heat between the steam and the water in this container.
+    /// Transfer heat between the gas and the liquid in this container.
+    /// The non-condensable gases are assumed to be well mixed with the gas, so both are kept at the same temperature.
     /// The transfer speed is dependent on the surface area parameter.
-    pub fn convect(&mut self, time: Time) {
+    pub fn convect(&mut self, time: Time) -> Result<(), Error> {
+        if self.liquid.mass().is_zero()
+            || (self.vapour.mass().is_zero() && self.gases.total_mass().is_zero())
+        {
+            // There is nothing to exchange heat with.
+            return Ok(());
+        }
+
+        self.equalise_gas_temperature()?;
+        let gas_temperature = if self.vapour.mass().is_zero() && !self.gases.total_mass().is_zero()
+        {
+            self.gases.temperature()
+        } else {
+            self.vapour.temperature()
+        };
+
         // The following will work once this is implemented: https://github.com/iliekturtles/uom/issues/447
-        // let temperature_difference = self.water.temperature - self.steam.temperature;
+        // let temperature_difference = self.liquid.temperature - gas_temperature;
         let temperature_difference =
             TemperatureInterval::new::<uom::si::temperature_interval::kelvin>(
-                self.water.temperature().get::<kelvin>() - self.steam.temperature().get::<kelvin>(),
+                self.liquid.temperature().get::<kelvin>() - gas_temperature.get::<kelvin>(),
             );
-        let transferred_energy = gas_liquid_heat_transfer_coefficient()
+        let transferred_energy = self.liquid.gas_liquid_heat_transfer_coefficient()
             * temperature_difference
             * self.surface_area
             * time;
-        self.steam += transferred_energy;
-        self.water -= transferred_energy;
+
+        let liquid = self.liquid.checked_add_energy(-transferred_energy)?;
+        let (vapour, gases) = if self.gases.total_mass().is_zero() {
+            (
+                self.vapour.checked_add_energy(transferred_energy)?,
+                self.gases,
+            )
+        } else {
+            // Split the energy such that gas and non-condensable gases stay at the same temperature.
+            let vapour_heat_capacity = self.vapour_heat_capacity();
+            let gases_heat_capacity = self.gases.heat_capacity().get::<joule_per_kelvin>();
+            let vapour_fraction =
+                vapour_heat_capacity / (vapour_heat_capacity + gases_heat_capacity);
+            let vapour = if vapour_fraction > 0.0 {
+                self.vapour
+                    .checked_add_energy(transferred_energy * vapour_fraction)?
+            } else {
+                self.vapour
+            };
+            let gases = self
+                .gases
+                .checked_add_energy(transferred_energy * (1.0 - vapour_fraction))?;
+            (vapour, gases)
+        };
+        self.liquid = liquid;
+        self.vapour = vapour;
+        self.gases = gases;
+        Ok(())
+    }
+
+    /// Exchange heat between the gas and the non-condensable gases, such that both have the same temperature.
+    fn equalise_gas_temperature(&mut self) -> Result<(), Error> {
+        if self.vapour.mass().is_zero() || self.gases.total_mass().is_zero() {
+            return Ok(());
+        }
+
+        let vapour_heat_capacity = self.vapour_heat_capacity();
+        let gases_heat_capacity = self.gases.heat_capacity().get::<joule_per_kelvin>();
+        let vapour_temperature = self.vapour.temperature().get::<kelvin>();
+        let gases_temperature = self.gases.temperature().get::<kelvin>();
+        let temperature = (vapour_heat_capacity * vapour_temperature
+            + gases_heat_capacity * gases_temperature)
+            / (vapour_heat_capacity + gases_heat_capacity);
+
+        let transferred_energy =
+            Energy::new::<joule>(gases_heat_capacity * (gases_temperature - temperature));
+        let vapour = self.vapour.checked_add_energy(transferred_energy)?;
+        self.gases = self.gases.checked_add_energy(-transferred_energy)?;
+        self.vapour = vapour;
+        Ok(())
+    }
+
+    /// The amount of energy in J needed to heat the gas by one Kelvin.
+    /// This uses the isobaric heat capacity of the substance, while the non-condensable gases use their isochoric one,
+    /// as each is how the temperature responds to [`Substance::checked_add_energy`] and [`GasMixture::checked_add_energy`] respectively.
+    /// Hence, splitting energy by these heat capacities keeps the gas and the non-condensable gases at the same temperature.
+    fn vapour_heat_capacity(&self) -> f64 {
+        (self.vapour.mass() * self.vapour.heat_capacity()).get::<joule_per_kelvin>()
+    }
+}
+
+impl WaterContainer {
+    /// The concentration of boron dissolved in the liquid water.
+    pub fn boron_concentration(&self) -> Ratio {
+        self.liquid.boron_concentration()
+    }
+
+    /// The mass of the ice in the liquid water.
+    pub fn ice_mass(&self) -> Mass {
+        self.liquid.ice_mass()
+    }
+
+    /// The activity of the given isotope in the liquid and the gas.
+    pub fn activity(&self, isotope: Isotope) -> Radioactivity {
+        self.liquid.activity(isotope) + self.vapour.activity(isotope)
+    }
+
+    /// Let the isotopes in the liquid and the gas decay for the given time.
+    pub fn decay(&mut self, time: Time) {
+        self.liquid.decay(time);
+        self.vapour.decay(time);
     }
 }
 
-impl Display for WaterContainer {
+impl<S: Substance> Display for Container<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Volume: {:.2}; Water: {:.2} at {:.2}; Steam: {:.2} at {:.2}; Pressure: {:.4}",
+            "Volume: {:.2}; {}: {:.2} at {:.2}; {}: {:.2} at {:.2}; Pressure: {:.4}",
             self.volume
                 .into_format_args(cubic_meter, DisplayStyle::Abbreviation),
-            self.water
+            S::LIQUID_NAME,
+            self.liquid
                 .mass()
                 .into_format_args(kilogram, DisplayStyle::Abbreviation),
-            self.water
+            self.liquid
                 .temperature()
                 .into_format_args(degree_celsius, DisplayStyle::Abbreviation),
-            self.steam
+            S::VAPOUR_NAME,
+            self.vapour
                 .mass()
                 .into_format_args(kilogram, DisplayStyle::Abbreviation),
-            self.steam
+            self.vapour
                 .temperature()
                 .into_format_args(degree_celsius, DisplayStyle::Abbreviation),
             self.pressure()
@@ -0,0 +1,316 @@
+use uom::si::{
+    area::square_meter,
+    f64::{Area, Mass, Ratio, ThermodynamicTemperature, Time, Volume},
+    mass::kilogram,
+    pressure::bar,
+    ratio::{part_per_million, ratio},
+    thermodynamic_temperature::degree_celsius,
+    time::second,
+    volume::cubic_meter,
+};
+
+use super::{Container, WaterContainer};
+use crate::substance::{
+    heavy_water::HeavyWater,
+    non_condensable_gas::{GasMixture, NonCondensableGas},
+    water::{equation_of_state::SteamEquationOfState, fusion_energy, melting_point, Phase, Water},
+    Substance,
+};
+use uom::num_traits::Zero;
+
+#[test]
+fn nitrogen_blanket() {
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(150.0);
+    let mut container = WaterContainer::new(
+        Volume::new::<cubic_meter>(10.0),
+        Area::new::<square_meter>(5.0),
+        Water::new(Mass::new::<kilogram>(5000.0), temperature),
+        Water::new_steam(Mass::new::<kilogram>(1.0), temperature),
+    )
+    .with_gases(GasMixture::new(
+        NonCondensableGas::Nitrogen,
+        Mass::new::<kilogram>(10.0),
+        temperature,
+    ));
+
+    for _ in 0..20 {
+        container.evaporate_condensate().unwrap();
+        container.convect(Time::new::<second>(1.0)).unwrap();
+    }
+
+    // The steam balances with the water on its own, the nitrogen only adds to the total pressure.
+    let vapour_pressure = container.vapour_pressure().get::<bar>();
+    let saturation_pressure = container.liquid().saturation_pressure().get::<bar>();
+    assert!((vapour_pressure - saturation_pressure).abs() < 0.05 * saturation_pressure);
+
+    let nitrogen_pressure = container
+        .gases()
+        .pressure(container.vapour_volume())
+        .get::<bar>();
+    assert!(nitrogen_pressure > 1.0);
+    assert!(
+        (container.pressure().get::<bar>() - vapour_pressure - nitrogen_pressure).abs() < 1e-10
+    );
+}
+
+#[test]
+fn steam_created_as_liquid() {
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(150.0);
+    let mut container = WaterContainer::new(
+        Volume::new::<cubic_meter>(10.0),
+        Area::new::<square_meter>(5.0),
+        Water::new(Mass::new::<kilogram>(5000.0), temperature),
+        Water::new(Mass::new::<kilogram>(10.0), temperature),
+    );
+    assert_eq!(container.vapour().phase(), Phase::Vapour);
+    assert!((container.vapour().temperature().get::<degree_celsius>() - 150.0).abs() < 1e-6);
+
+    container.evaporate_condensate().unwrap();
+    assert_eq!(container.vapour().phase(), Phase::Vapour);
+}
+
+#[test]
+fn empty_vapour() {
+    let mut container = WaterContainer::new(
+        Volume::new::<cubic_meter>(10.0),
+        Area::new::<square_meter>(5.0),
+        Water::new(
+            Mass::new::<kilogram>(5000.0),
+            ThermodynamicTemperature::new::<degree_celsius>(150.0),
+        ),
+        Water::zero(),
+    );
+
+    for _ in 0..20 {
+        container.evaporate_condensate().unwrap();
+        container.convect(Time::new::<second>(1.0)).unwrap();
+    }
+
+    assert!(container.vapour().mass() > Mass::zero());
+    assert_eq!(container.vapour().phase(), Phase::Vapour);
+    let saturation_pressure = container.liquid().saturation_pressure().get::<bar>();
+    assert!(
+        (container.pressure().get::<bar>() - saturation_pressure).abs()
+            < 0.05 * saturation_pressure
+    );
+}
+
+#[test]
+fn display() {
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(50.0);
+    let volume = Volume::new::<cubic_meter>(1.0);
+    let area = Area::new::<square_meter>(1.0);
+    let mass = Mass::new::<kilogram>(100.0);
+
+    let water = WaterContainer::new(volume, area, Water::new(mass, temperature), Water::zero());
+    assert!(water.to_string().contains("; Water: "));
+    assert!(water.to_string().contains("; Steam: "));
+
+    let heavy_water = Container::new(
+        volume,
+        area,
+        HeavyWater::new(mass, temperature),
+        HeavyWater::zero(),
+    );
+    assert!(heavy_water.to_string().contains("; Heavy water: "));
+    assert!(!heavy_water.to_string().contains("Steam"));
+}
+
+#[test]
+fn supercritical() {
+    let mut container = WaterContainer::new(
+        Volume::new::<cubic_meter>(10.0),
+        Area::new::<square_meter>(5.0),
+        Water::new(
+            Mass::new::<kilogram>(3000.0),
+            ThermodynamicTemperature::new::<degree_celsius>(400.0),
+        ),
+        Water::new_steam(
+            Mass::new::<kilogram>(100.0),
+            ThermodynamicTemperature::new::<degree_celsius>(400.0),
+        ),
+    );
+
+    container.evaporate_condensate().unwrap();
+    container.convect(Time::new::<second>(1.0)).unwrap();
+
+    assert!(container.is_supercritical());
+    assert_eq!(container.liquid().phase(), Phase::Supercritical);
+    assert!(container.vapour().mass().is_zero());
+    assert!((container.liquid().mass().get::<kilogram>() - 3100.0).abs() < 1e-6);
+    assert!(container.vapour_volume().is_zero());
+
+    // IAPWS-IF97 gives about 300 bar for a density of 310 kg/m^3 at slightly above 400°C.
+    let pressure = container.pressure().get::<bar>();
+    assert!(pressure > 250.0 && pressure < 350.0, "{pressure}");
+}
+
+#[test]
+fn full_of_liquid() {
+    let container = |temperature| {
+        let mut container = WaterContainer::new(
+            Volume::new::<cubic_meter>(1.0),
+            Area::new::<square_meter>(1.0),
+            Water::new(
+                Mass::new::<kilogram>(800.0),
+                ThermodynamicTemperature::new::<degree_celsius>(temperature),
+            ),
+            Water::new_steam(
+                Mass::new::<kilogram>(1.0),
+                ThermodynamicTemperature::new::<degree_celsius>(temperature),
+            ),
+        );
+        container.evaporate_condensate().unwrap();
+        container
+    };
+
+    let cold = container(250.0);
+    assert!(cold.is_full());
+    assert!(cold.vapour().mass().is_zero());
+    assert!(cold.vapour_volume().is_zero());
+    let cold_pressure = cold.pressure();
+    assert!(cold_pressure.is_finite());
+    assert!(cold_pressure > cold.liquid().saturation_pressure());
+
+    // Thermal expansion of the compressed liquid raises the pressure a lot.
+    let hot = container(255.0);
+    assert!(
+        hot.pressure()
+            > cold_pressure
+                + (hot.liquid().saturation_pressure() - cold.liquid().saturation_pressure()) * 2.0
+    );
+
+    // A bubble of non-condensable gas is squeezed until it has the pressure of the liquid.
+    let with_bubble = hot.clone().with_gases(GasMixture::new(
+        NonCondensableGas::Nitrogen,
+        Mass::new::<kilogram>(0.1),
+        ThermodynamicTemperature::new::<degree_celsius>(255.0),
+    ));
+    assert!(with_bubble.is_full());
+    assert!(with_bubble.vapour_volume() > Volume::zero());
+    assert!(with_bubble.pressure() > hot.pressure());
+    let bubble_pressure = with_bubble
+        .gases()
+        .pressure(with_bubble.vapour_volume())
+        .get::<bar>();
+    assert!((with_bubble.pressure().get::<bar>() - bubble_pressure).abs() < 1e-3);
+}
+
+#[test]
+fn full_of_liquid_without_vapour() {
+    let mut container = WaterContainer::new(
+        Volume::new::<cubic_meter>(1.0),
+        Area::new::<square_meter>(1.0),
+        Water::new(
+            Mass::new::<kilogram>(1000.0),
+            ThermodynamicTemperature::new::<degree_celsius>(20.0),
+        ),
+        Water::zero(),
+    );
+    container.evaporate_condensate().unwrap();
+
+    assert!(container.is_full());
+    assert!(container.vapour().mass().is_zero());
+    let pressure = container.pressure();
+    assert!(pressure.is_finite());
+    assert!(pressure > container.liquid().saturation_pressure());
+}
+
+#[test]
+fn boron_stays_in_liquid() {
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(300.0);
+    let mut container = WaterContainer::new(
+        Volume::new::<cubic_meter>(10.0),
+        Area::new::<square_meter>(5.0),
+        Water::new(Mass::new::<kilogram>(5000.0), temperature)
+            .with_boron_concentration(Ratio::new::<part_per_million>(1200.0)),
+        Water::new_steam(Mass::new::<kilogram>(1.0), temperature),
+    );
+
+    for _ in 0..10 {
+        container.evaporate_condensate().unwrap();
+        container.convect(Time::new::<second>(1.0)).unwrap();
+    }
+
+    assert!(container.vapour().mass() > Mass::zero());
+    assert!(container.vapour().boron_concentration().is_zero());
+    assert!(container.boron_concentration().get::<part_per_million>() > 1200.0);
+    assert!((container.liquid().boron_mass().get::<kilogram>() - 6.0).abs() < 1e-9);
+}
+
+#[test]
+fn ice_in_pool() {
+    let ice = Water::new(
+        Mass::new::<kilogram>(2000.0),
+        ThermodynamicTemperature::new::<degree_celsius>(-20.0),
+    );
+    let water = Water::new(
+        Mass::new::<kilogram>(3000.0),
+        ThermodynamicTemperature::new::<degree_celsius>(10.0),
+    );
+    let container = WaterContainer::new(
+        Volume::new::<cubic_meter>(10.0),
+        Area::new::<square_meter>(5.0),
+        ice + water,
+        Water::new_steam(
+            Mass::new::<kilogram>(0.01),
+            ThermodynamicTemperature::new::<degree_celsius>(0.0),
+        ),
+    );
+
+    // The ice cools the water down to the melting point, but does not melt completely.
+    assert_eq!(container.liquid().temperature(), melting_point());
+    let frozen_fraction = ((Water::new(Mass::new::<kilogram>(1.0), melting_point())
+        .specific_enthalpy()
+        - container.liquid().specific_enthalpy())
+        / fusion_energy())
+    .get::<ratio>();
+    let ice_mass = container.ice_mass().get::<kilogram>();
+    assert!(ice_mass > 0.0 && ice_mass < 2000.0);
+    assert!((ice_mass - 5000.0 * frozen_fraction).abs() < 1e-6);
+
+    // Ice floats, so the pool takes more volume than liquid water at the melting point.
+    let liquid_volume = Water::new(Mass::new::<kilogram>(5000.0), melting_point()).volume();
+    assert!(container.liquid_volume() > liquid_volume);
+}
+
+#[test]
+fn real_gas_steam() {
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(300.0);
+    let container = |equation_of_state| {
+        let mut container = WaterContainer::new(
+            Volume::new::<cubic_meter>(10.0),
+            Area::new::<square_meter>(5.0),
+            Water::new(Mass::new::<kilogram>(5000.0), temperature)
+                .with_equation_of_state(equation_of_state),
+            Water::new_steam(Mass::new::<kilogram>(1.0), temperature)
+                .with_equation_of_state(equation_of_state),
+        );
+        for _ in 0..20 {
+            container.evaporate_condensate().unwrap();
+        }
+        container
+    };
+
+    // Both reach the saturation pressure, but real steam is denser, so more of the water evaporates.
+    let ideal_gas = container(SteamEquationOfState::IdealGas);
+    let real_gas = container(SteamEquationOfState::PengRobinson);
+    for container in [&ideal_gas, &real_gas] {
+        let saturation_pressure = container.liquid().saturation_pressure().get::<bar>();
+        assert!(
+            (container.pressure().get::<bar>() - saturation_pressure).abs()
+                < 0.05 * saturation_pressure
+        );
+    }
+    assert!(real_gas.vapour().mass() > ideal_gas.vapour().mass() * 1.2);
+
+    // The same steam has a lower pressure as a real gas.
+    let steam = Water::new_steam(Mass::new::<kilogram>(200.0), temperature);
+    let volume = Volume::new::<cubic_meter>(10.0);
+    assert!(
+        steam
+            .with_equation_of_state(SteamEquationOfState::PengRobinson)
+            .pressure(volume)
+            < steam.pressure(volume)
+    );
+}
@@ -23,6 +23,18 @@ pub struct ResistanceNetwork<Types: TypeParamerisation> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "<Types::ElectricalNodeTypes as ElectricalNodeTypes>::ConstantVoltageSourceWrapper: serde::Serialize, \
+            <Types::ElectricalNodeTypes as ElectricalNodeTypes>::BranchWrapper: serde::Serialize, \
+            <Types::ElectricalNodeTypes as ElectricalNodeTypes>::ConstantResistanceConsumerWrapper: serde::Serialize",
+        deserialize = "<Types::ElectricalNodeTypes as ElectricalNodeTypes>::ConstantVoltageSourceWrapper: serde::Deserialize<'de>, \
+            <Types::ElectricalNodeTypes as ElectricalNodeTypes>::BranchWrapper: serde::Deserialize<'de>, \
+            <Types::ElectricalNodeTypes as ElectricalNodeTypes>::ConstantResistanceConsumerWrapper: serde::Deserialize<'de>",
+    ))
+)]
 pub enum ElectricalNode<Types: TypeParamerisation> {
     ConstantVoltageSource(<<Types as TypeParamerisation>::ElectricalNodeTypes as ElectricalNodeTypes>::ConstantVoltageSourceWrapper),
     Branch(<<Types as TypeParamerisation>::ElectricalNodeTypes as ElectricalNodeTypes>::BranchWrapper),
@@ -258,6 +270,27 @@ impl<Types: TypeParamerisation> ResistanceNetwork<Types> {
     }
 }
 
+/// Only the graph is serialised, with resistances in ohm, and the voltage indexes are recomputed when deserialising.
+#[cfg(feature = "serde")]
+impl<Types: TypeParamerisation> serde::Serialize for ResistanceNetwork<Types>
+where
+    ElectricalNode<Types>: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.graph.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, Types: TypeParamerisation> serde::Deserialize<'de> for ResistanceNetwork<Types>
+where
+    ElectricalNode<Types>: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        UnGraph::deserialize(deserializer).map(Self::new)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::cell::RefCell;
@@ -281,6 +314,7 @@ mod tests {
     };
 
     #[derive(Default, Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     struct TestVoltageSource {
         pub voltage: ElectricPotential,
         pub inner_resistance: ElectricalResistance,
@@ -311,6 +345,7 @@ mod tests {
     }
 
     #[derive(Default, Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     struct TestBranch {
         pub voltage: ElectricPotential,
     }
@@ -328,6 +363,7 @@ mod tests {
     }
 
     #[derive(Default, Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     struct TestResistanceConsumer {
         pub voltage: ElectricPotential,
         pub inner_resistance: ElectricalResistance,
@@ -491,4 +527,33 @@ mod tests {
             &[(1.0, 0.0), (2.0, 1.0), (1.0, 0.0), (0.5, 0.5), (0.5, 0.5)],
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut graph = UnGraph::default();
+        let n = [
+            graph.add_node(
+                ElectricalNode::<TestTypeParameterisation>::ConstantVoltageSource(
+                    TestVoltageSource::new(1.0).into(),
+                ),
+            ),
+            graph.add_node(ElectricalNode::Branch(TestBranch::new().into())),
+            graph.add_node(ElectricalNode::ConstantResistanceConsumer(
+                TestResistanceConsumer::new(1.0).into(),
+            )),
+        ];
+        graph.add_edge(n[0], n[1], ElectricalResistance::new::<ohm>(1.0));
+        graph.add_edge(n[1], n[2], ElectricalResistance::new::<ohm>(1.0));
+
+        let json = serde_json::to_string(&ResistanceNetwork::new(graph)).unwrap();
+        let mut resistance_network: ResistanceNetwork<TestTypeParameterisation> =
+            serde_json::from_str(&json).unwrap();
+        resistance_network.update_voltages().unwrap();
+
+        verify_voltage_currents(
+            &resistance_network,
+            &[(1.0, 1.0 / 3.0), (2.0 / 3.0, 0.0), (1.0 / 3.0, 1.0 / 3.0)],
+        );
+    }
 }
@@ -7,6 +7,76 @@ pub enum Error {
     #[error("the operation left some mass with a temperature below zero Kelvin")]
     NonPositiveTemperature,
 
+    #[error("cannot take {requested} kg from {available} kg")]
+    InsufficientMass { requested: f64, available: f64 },
+
+    #[error("cannot take a negative mass of {mass} kg")]
+    NegativeMass { mass: f64 },
+
+    #[error("cannot transfer energy to or from zero mass")]
+    ZeroMassEnergyTransfer,
+
+    #[error("cannot take {requested} m^3 from {available} m^3")]
+    VolumeExceedsAvailable { requested: f64, available: f64 },
+
+    #[error("expected {expected} water, but it is {actual}")]
+    UnexpectedPhase { expected: String, actual: String },
+
     #[error("the conductance matrix used for computing the resistance network voltages is not invertible: {matrix:?}")]
     NonInvertibleConductanceMatrix { matrix: DMatrix<f64> },
+
+    #[error("the state is outside the range of validity of IAPWS-IF97: {state}")]
+    OutsideIapwsIf97Range { state: String },
+
+    #[error("the steam quality {quality} is not between zero and one")]
+    InvalidSteamQuality { quality: f64 },
+
+    #[error("the isentropic efficiency {efficiency} is not above zero and at most one")]
+    InvalidIsentropicEfficiency { efficiency: f64 },
+
+    #[error("an interpolation table needs at least one entry")]
+    EmptyInterpolationTable,
+
+    #[error("the keys of an interpolation table are not strictly increasing at entry {index}")]
+    UnsortedInterpolationTable { index: usize },
+
+    #[error(
+        "the {axis} keys of an interpolation grid are not strictly increasing at entry {index}"
+    )]
+    UnsortedInterpolationGrid { axis: &'static str, index: usize },
+
+    #[error("the values of an interpolation grid are not {rows} rows of {columns} values each")]
+    InvalidInterpolationGridShape { rows: usize, columns: usize },
+
+    #[error("the key {key} is outside of the range from {minimum} to {maximum} of the interpolation table {table}")]
+    InterpolationKeyOutOfRange {
+        table: String,
+        key: f64,
+        minimum: f64,
+        maximum: f64,
+    },
+
+    #[error("invalid interpolation table data in line {line}: {message}")]
+    InvalidTableData { line: usize, message: String },
+
+    #[error("cannot read the interpolation table file {path}: {source}")]
+    ReadTableFile {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error("snapshot format version {version} is not supported, expected version {supported}")]
+    UnsupportedFormatVersion { version: u32, supported: u32 },
+
+    #[error("{coolant} would freeze at {temperature} K")]
+    LiquidMetalFrozen { coolant: String, temperature: f64 },
+
+    #[error("{coolant} would boil at {temperature} K")]
+    LiquidMetalBoiling { coolant: String, temperature: f64 },
+
+    #[error("cannot mix {coolant} with {other}")]
+    MixedLiquidMetalCoolants { coolant: String, other: String },
+
+    #[error("{coolant} is always liquid, so it cannot evaporate or condensate")]
+    LiquidMetalPhaseChange { coolant: String },
 }
@@ -0,0 +1,160 @@
+//! Loading interpolation tables from data files at runtime, e.g. vendor pump curves or corrected property data.
+//!
+//! CSV files have one entry per line with the key and the value separated by a comma.
+//! Empty lines and lines starting with `#` are ignored, and the first entry may be replaced by a header naming the columns.
+//!
+//! TOML files are only supported with the `toml` feature. They contain the entries as an array of pairs,
+//! and optionally the name of the table and the [`InterpolationKind`]:
+//!
+//! ```toml
+//! name = "rod worth"
+//! interpolation_kind = "MonotoneCubic"
+//! table = [
+//!     [0.0, 0.0],
+//!     [50.0, 0.4],
+//!     [100.0, 1.0],
+//! ]
+//! ```
+
+use std::path::Path;
+
+use crate::error::{Error, Result};
+#[cfg(feature = "toml")]
+use crate::interpolation_table::InterpolationKind;
+use crate::interpolation_table::{LimitBehaviour, LinearInterpolationTable};
+
+#[cfg(test)]
+mod tests;
+
+impl LinearInterpolationTable {
+    /// Returns an error with the line number if a line is not a pair of numbers,
+    /// or the table cannot be created, see [`LinearInterpolationTable::try_new`].
+    pub fn from_csv(limit_behaviour: LimitBehaviour, contents: &str) -> Result<Self> {
+        let mut entries = Vec::new();
+        let mut has_header = false;
+        for (index, line) in contents.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<_> = line.split(',').map(str::trim).collect();
+            if fields.len() != 2 {
+                return Err(Error::InvalidTableData {
+                    line: line_number,
+                    message: format!("expected two columns, but found {}", fields.len()),
+                });
+            }
+            match (fields[0].parse(), fields[1].parse()) {
+                (Ok(key), Ok(value)) => entries.push((line_number, (key, value))),
+                (Err(_), Err(_)) if entries.is_empty() && !has_header => has_header = true,
+                (key, _) => {
+                    let field = if key.is_err() { fields[0] } else { fields[1] };
+                    return Err(Error::InvalidTableData {
+                        line: line_number,
+                        message: format!("`{field}` is not a number"),
+                    });
+                }
+            }
+        }
+
+        try_new_with_lines(limit_behaviour, entries)
+    }
+
+    /// Like [`LinearInterpolationTable::from_csv`], with the path of the file as name of the table.
+    pub fn read_csv(limit_behaviour: LimitBehaviour, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        Self::from_csv(limit_behaviour, &read(path)?)
+            .map(|table| table.with_name(path.display().to_string()))
+    }
+
+    /// Returns an error with the line number if the contents are not a valid table file,
+    /// or the table cannot be created, see [`LinearInterpolationTable::try_new`].
+    #[cfg(feature = "toml")]
+    pub fn from_toml(limit_behaviour: LimitBehaviour, contents: &str) -> Result<Self> {
+        from_toml(limit_behaviour, contents, None)
+    }
+
+    /// Like [`LinearInterpolationTable::from_toml`], with the path of the file as name of the table if the file does not name it.
+    #[cfg(feature = "toml")]
+    pub fn read_toml(limit_behaviour: LimitBehaviour, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        from_toml(
+            limit_behaviour,
+            &read(path)?,
+            Some(path.display().to_string()),
+        )
+    }
+}
+
+#[cfg(feature = "toml")]
+fn from_toml(
+    limit_behaviour: LimitBehaviour,
+    contents: &str,
+    default_name: Option<String>,
+) -> Result<LinearInterpolationTable> {
+    #[derive(serde::Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct TableFile {
+        name: Option<String>,
+        #[serde(default)]
+        interpolation_kind: InterpolationKind,
+        table: Vec<toml::Spanned<(f64, f64)>>,
+    }
+
+    let line_number = |offset: usize| contents[..offset].matches('\n').count() + 1;
+    let file: TableFile = toml::from_str(contents).map_err(|error| Error::InvalidTableData {
+        line: error.span().map_or(1, |span| line_number(span.start)),
+        message: error.message().to_string(),
+    })?;
+    let entries = file
+        .table
+        .into_iter()
+        .map(|entry| (line_number(entry.span().start), entry.into_inner()))
+        .collect();
+
+    let table = try_new_with_lines(limit_behaviour, entries)?
+        .with_interpolation_kind(file.interpolation_kind);
+    Ok(match file.name.or(default_name) {
+        Some(name) => table.with_name(name),
+        None => table,
+    })
+}
+
+fn read(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|source| Error::ReadTableFile {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+/// Create a table from entries with the numbers of the lines they were read from,
+/// reporting non-finite numbers and unsorted keys at their line.
+fn try_new_with_lines(
+    limit_behaviour: LimitBehaviour,
+    entries: Vec<(usize, (f64, f64))>,
+) -> Result<LinearInterpolationTable> {
+    if let Some((line, (key, value))) = entries
+        .iter()
+        .find(|(_, (key, value))| !key.is_finite() || !value.is_finite())
+    {
+        return Err(Error::InvalidTableData {
+            line: *line,
+            message: format!("the entry ({key}, {value}) is not finite"),
+        });
+    }
+
+    let table = entries.iter().map(|(_, entry)| *entry).collect();
+    LinearInterpolationTable::try_new(limit_behaviour, table).map_err(|error| match error {
+        Error::UnsortedInterpolationTable { index } => {
+            let (line, (key, _)) = entries[index];
+            let (_, (previous, _)) = entries[index - 1];
+            Error::InvalidTableData {
+                line,
+                message: format!("the key {key} is not greater than the previous key {previous}"),
+            }
+        }
+        error => error,
+    })
+}
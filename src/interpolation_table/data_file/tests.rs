@@ -0,0 +1,115 @@
+use crate::error::Error;
+use crate::interpolation_table::{LimitBehaviour, LinearInterpolationTable};
+
+fn error_line(result: crate::error::Result<LinearInterpolationTable>) -> usize {
+    match result {
+        Err(Error::InvalidTableData { line, .. }) => line,
+        other => panic!("expected invalid table data, got {other:?}"),
+    }
+}
+
+#[test]
+fn csv() {
+    let contents = "# pump curve\n\
+                    flow (kg/s), head (m)\n\
+                    \n\
+                    0.0, 120.0\n\
+                    500.0,110.5\r\n\
+                    1000.0, 80.0\n";
+    let table = LinearInterpolationTable::from_csv(LimitBehaviour::Panic, contents).unwrap();
+    assert!((table.get(250.0) - 115.25).abs() < 1e-10);
+    assert!((table.get(1000.0) - 80.0).abs() < 1e-10);
+    assert!(table.try_get(1001.0).is_err());
+}
+
+#[test]
+fn invalid_csv() {
+    let load = |contents| LinearInterpolationTable::from_csv(LimitBehaviour::Clamp, contents);
+
+    assert_eq!(error_line(load("key, value\n0.0, 1.0\n1.0, 2.0, 3.0\n")), 3);
+    assert_eq!(error_line(load("0.0, 1.0\n1.0, high\n")), 2);
+    // Only the first entry may be a header.
+    assert_eq!(error_line(load("0.0, 1.0\nkey, value\n")), 2);
+    assert_eq!(error_line(load("0.0, 1.0\n\n# comment\n2.0, inf\n")), 4);
+
+    assert_eq!(
+        error_line(load("0.0, 1.0\n2.0, 2.0\n# corrected\n2.0, 3.0\n")),
+        4
+    );
+    let error = load("0.0, 1.0\n2.0, 2.0\n1.0, 3.0\n").unwrap_err();
+    assert!(
+        error.to_string().contains("line 3")
+            && error
+                .to_string()
+                .contains("not greater than the previous key 2"),
+        "{error}"
+    );
+
+    assert!(matches!(
+        load("key, value\n"),
+        Err(Error::EmptyInterpolationTable)
+    ));
+}
+
+#[test]
+fn read_csv() {
+    let path =
+        std::env::temp_dir().join(format!("nuklear_read_csv_test_{}.csv", std::process::id()));
+    std::fs::write(&path, "0.0, 1.0\n2.0, 2.0\n").unwrap();
+    let table = LinearInterpolationTable::read_csv(LimitBehaviour::Clamp, &path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!((table.get(1.0) - 1.5).abs() < 1e-10);
+    assert_eq!(table.name(), path.display().to_string());
+
+    assert!(matches!(
+        LinearInterpolationTable::read_csv(LimitBehaviour::Clamp, &path),
+        Err(Error::ReadTableFile { .. })
+    ));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml() {
+    use crate::interpolation_table::InterpolationKind;
+
+    let contents = r#"
+name = "rod worth"
+interpolation_kind = "MonotoneCubic"
+table = [
+    [0.0, 0.0],
+    [50.0, 0.4],
+    [100.0, 1.0],
+]
+"#;
+    let table = LinearInterpolationTable::from_toml(LimitBehaviour::Clamp, contents).unwrap();
+    assert_eq!(table.name(), "rod worth");
+    assert_eq!(table.interpolation_kind(), InterpolationKind::MonotoneCubic);
+    assert!((table.get(50.0) - 0.4).abs() < 1e-10);
+
+    let load = |contents| LinearInterpolationTable::from_toml(LimitBehaviour::Clamp, contents);
+    assert_eq!(
+        error_line(load(
+            "table = [\n    [0.0, 0.0],\n    [1.0, 1.0],\n    [0.5, 2.0],\n]\n"
+        )),
+        4
+    );
+    assert_eq!(
+        error_line(load("table = [\n    [0.0, 0.0],\n    [1.0, \"one\"],\n]\n")),
+        3
+    );
+    assert_eq!(
+        error_line(load("\nname = \"misspelled\"\nkind = 1\ntable = []\n")),
+        3
+    );
+    assert_eq!(error_line(load("table = [[0.0, nan]]\n")), 1);
+
+    let path = std::env::temp_dir().join(format!(
+        "nuklear_read_toml_test_{}.toml",
+        std::process::id()
+    ));
+    std::fs::write(&path, "table = [[0.0, 1.0], [2.0, 2.0]]\n").unwrap();
+    let table = LinearInterpolationTable::read_toml(LimitBehaviour::Clamp, &path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(table.name(), path.display().to_string());
+    assert_eq!(table.interpolation_kind(), InterpolationKind::Linear);
+}
@@ -0,0 +1,303 @@
+//! Interpolation tables with two keys, e.g. for properties depending on temperature and pressure.
+//!
+//! The values are given on a rectilinear grid, i.e. for every combination of the keys along both axes.
+
+use std::cmp::Ordering;
+
+use crate::error::{Error, Result};
+use crate::interpolation_table::{monotone_cubic_derivatives, LimitBehaviour};
+
+#[cfg(test)]
+mod tests;
+
+/// How values between the keys of a grid are interpolated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GridInterpolationKind {
+    /// Linear along both axes, which has kinks at the keys.
+    #[default]
+    Bilinear,
+    /// Bicubic Hermite, which has continuous first derivatives.
+    /// The derivatives at the keys are those of [`super::InterpolationKind::MonotoneCubic`] along each axis,
+    /// so data that is monotone along an axis stays monotone along the grid lines.
+    Bicubic,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GridInterpolationTable {
+    x_keys: Vec<f64>,
+    y_keys: Vec<f64>,
+    /// The value at `(x_keys[i], y_keys[j])` is `values[i][j]`.
+    values: Vec<Vec<f64>>,
+    limit_behaviour: LimitBehaviour,
+    interpolation_kind: GridInterpolationKind,
+    /// The name used in errors.
+    name: String,
+    /// The derivatives by x, by y, and by both at the keys for bicubic interpolation, empty for bilinear interpolation.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    derivatives: Vec<Vec<[f64; 3]>>,
+}
+
+impl GridInterpolationTable {
+    /// Panics if a table with these keys and values cannot be created, see [`GridInterpolationTable::try_new`].
+    pub fn new(
+        limit_behaviour: LimitBehaviour,
+        x_keys: Vec<f64>,
+        y_keys: Vec<f64>,
+        values: Vec<Vec<f64>>,
+    ) -> Self {
+        Self::try_new(limit_behaviour, x_keys, y_keys, values)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Returns an error if there are no keys along an axis, the keys are not strictly increasing,
+    /// or there is not exactly one row of values for each x key, each with one value for each y key.
+    pub fn try_new(
+        limit_behaviour: LimitBehaviour,
+        x_keys: Vec<f64>,
+        y_keys: Vec<f64>,
+        values: Vec<Vec<f64>>,
+    ) -> Result<Self> {
+        for (axis, keys) in [("x", &x_keys), ("y", &y_keys)] {
+            if keys.is_empty() {
+                return Err(Error::EmptyInterpolationTable);
+            }
+            if let Some(index) = keys
+                .windows(2)
+                .position(|pair| pair[0].partial_cmp(&pair[1]) != Some(Ordering::Less))
+            {
+                return Err(Error::UnsortedInterpolationGrid {
+                    axis,
+                    index: index + 1,
+                });
+            }
+        }
+        if values.len() != x_keys.len() || values.iter().any(|row| row.len() != y_keys.len()) {
+            return Err(Error::InvalidInterpolationGridShape {
+                rows: x_keys.len(),
+                columns: y_keys.len(),
+            });
+        }
+
+        Ok(Self {
+            x_keys,
+            y_keys,
+            values,
+            limit_behaviour,
+            interpolation_kind: GridInterpolationKind::Bilinear,
+            name: String::from("unnamed"),
+            derivatives: Vec::new(),
+        })
+    }
+
+    /// Set the name of the table that is shown in errors.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..self
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn with_interpolation_kind(self, interpolation_kind: GridInterpolationKind) -> Self {
+        let derivatives = match interpolation_kind {
+            GridInterpolationKind::Bilinear => Vec::new(),
+            GridInterpolationKind::Bicubic => self.bicubic_derivatives(),
+        };
+        Self {
+            interpolation_kind,
+            derivatives,
+            ..self
+        }
+    }
+
+    pub fn interpolation_kind(&self) -> GridInterpolationKind {
+        self.interpolation_kind
+    }
+
+    /// Panics if a key is NaN, or outside of the table with [`LimitBehaviour::Panic`].
+    pub fn get(&self, x: f64, y: f64) -> f64 {
+        self.try_get(x, y).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Returns an error if a key is NaN, or outside of the table with [`LimitBehaviour::Panic`].
+    /// With [`LimitBehaviour::Clamp`], both keys are clamped separately,
+    /// and with [`LimitBehaviour::Extrapolate`], the value continues linearly with the slopes at the closest point of the grid.
+    pub fn try_get(&self, x: f64, y: f64) -> Result<f64> {
+        let x_range = (self.x_keys[0], *self.x_keys.last().unwrap());
+        let y_range = (self.y_keys[0], *self.y_keys.last().unwrap());
+        for (key, (minimum, maximum)) in [(x, x_range), (y, y_range)] {
+            if key.is_nan()
+                || (self.limit_behaviour == LimitBehaviour::Panic
+                    && !(minimum..=maximum).contains(&key))
+            {
+                return Err(Error::InterpolationKeyOutOfRange {
+                    table: self.name.clone(),
+                    key,
+                    minimum,
+                    maximum,
+                });
+            }
+        }
+
+        let clamped_x = x.clamp(x_range.0, x_range.1);
+        let clamped_y = y.clamp(y_range.0, y_range.1);
+        let mut value = self.interpolate(clamped_x, clamped_y, false, false);
+        if self.limit_behaviour == LimitBehaviour::Extrapolate {
+            if x != clamped_x {
+                value += (x - clamped_x) * self.interpolate(clamped_x, clamped_y, true, false);
+            }
+            if y != clamped_y {
+                value += (y - clamped_y) * self.interpolate(clamped_x, clamped_y, false, true);
+            }
+        }
+        Ok(value)
+    }
+
+    /// The value, or its derivative by x or y, at a point inside of the grid.
+    fn interpolate(&self, x: f64, y: f64, by_x: bool, by_y: bool) -> f64 {
+        let (i, x_weights) = weights(self.interpolation_kind, &self.x_keys, x, by_x);
+        let (j, y_weights) = weights(self.interpolation_kind, &self.y_keys, y, by_y);
+
+        let mut result = 0.0;
+        for (a, i) in [i, (i + 1).min(self.x_keys.len() - 1)]
+            .into_iter()
+            .enumerate()
+        {
+            for (b, j) in [j, (j + 1).min(self.y_keys.len() - 1)]
+                .into_iter()
+                .enumerate()
+            {
+                let [derivative_by_x, derivative_by_y, derivative_by_both] =
+                    self.derivatives.get(i).map_or([0.0; 3], |row| row[j]);
+                let (value_x, derivative_x) = x_weights[a];
+                let (value_y, derivative_y) = y_weights[b];
+                result += value_x * value_y * self.values[i][j]
+                    + derivative_x * value_y * derivative_by_x
+                    + value_x * derivative_y * derivative_by_y
+                    + derivative_x * derivative_y * derivative_by_both;
+            }
+        }
+        result
+    }
+
+    fn bicubic_derivatives(&self) -> Vec<Vec<[f64; 3]>> {
+        let along_x = |value: &dyn Fn(usize) -> f64| {
+            let line: Vec<_> = (0..self.x_keys.len())
+                .map(|i| (self.x_keys[i], value(i)))
+                .collect();
+            monotone_cubic_derivatives(&line)
+        };
+
+        let by_y: Vec<Vec<f64>> = self
+            .values
+            .iter()
+            .map(|row| {
+                let line: Vec<_> = self
+                    .y_keys
+                    .iter()
+                    .copied()
+                    .zip(row.iter().copied())
+                    .collect();
+                monotone_cubic_derivatives(&line)
+            })
+            .collect();
+        let by_x: Vec<Vec<f64>> = (0..self.y_keys.len())
+            .map(|j| along_x(&|i| self.values[i][j]))
+            .collect();
+        let by_both: Vec<Vec<f64>> = (0..self.y_keys.len())
+            .map(|j| along_x(&|i| by_y[i][j]))
+            .collect();
+
+        (0..self.x_keys.len())
+            .map(|i| {
+                (0..self.y_keys.len())
+                    .map(|j| [by_x[j][i], by_y[i][j], by_both[j][i]])
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// The index of the key at the start of the segment containing the given key,
+/// and the weights of the values and the derivatives at the start and at the end of the segment.
+/// With `slope`, these are the weights for the derivative by the key.
+fn weights(
+    interpolation_kind: GridInterpolationKind,
+    keys: &[f64],
+    key: f64,
+    slope: bool,
+) -> (usize, [(f64, f64); 2]) {
+    if keys.len() == 1 {
+        let value_weight = if slope { 0.0 } else { 1.0 };
+        return (0, [(value_weight, 0.0), (0.0, 0.0)]);
+    }
+
+    let index = keys
+        .partition_point(|other| *other <= key)
+        .clamp(1, keys.len() - 1)
+        - 1;
+    let width = keys[index + 1] - keys[index];
+    let t = (key - keys[index]) / width;
+    let weights = match (interpolation_kind, slope) {
+        (GridInterpolationKind::Bilinear, false) => [(1.0 - t, 0.0), (t, 0.0)],
+        (GridInterpolationKind::Bilinear, true) => [(-1.0 / width, 0.0), (1.0 / width, 0.0)],
+        // Cubic Hermite basis functions.
+        (GridInterpolationKind::Bicubic, false) => {
+            let end = t * t * (3.0 - 2.0 * t);
+            [
+                (1.0 - end, t * (1.0 - t) * (1.0 - t) * width),
+                (end, t * t * (t - 1.0) * width),
+            ]
+        }
+        (GridInterpolationKind::Bicubic, true) => {
+            let end = 6.0 * t * (1.0 - t) / width;
+            [
+                (-end, (1.0 - t) * (1.0 - 3.0 * t)),
+                (end, t * (3.0 * t - 2.0)),
+            ]
+        }
+    };
+    (index, weights)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GridInterpolationTable {
+    /// Deserialise a table, checking it like [`GridInterpolationTable::try_new`].
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Unchecked {
+            x_keys: Vec<f64>,
+            y_keys: Vec<f64>,
+            values: Vec<Vec<f64>>,
+            limit_behaviour: LimitBehaviour,
+            #[serde(default)]
+            interpolation_kind: GridInterpolationKind,
+            name: Option<String>,
+        }
+
+        let Unchecked {
+            x_keys,
+            y_keys,
+            values,
+            limit_behaviour,
+            interpolation_kind,
+            name,
+        } = Unchecked::deserialize(deserializer)?;
+        Self::try_new(limit_behaviour, x_keys, y_keys, values)
+            .map(|table| {
+                let table = table.with_interpolation_kind(interpolation_kind);
+                match name {
+                    Some(name) => table.with_name(name),
+                    None => table,
+                }
+            })
+            .map_err(serde::de::Error::custom)
+    }
+}
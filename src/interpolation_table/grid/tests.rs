@@ -0,0 +1,144 @@
+use super::{GridInterpolationKind, GridInterpolationTable};
+use crate::error::Error;
+use crate::interpolation_table::LimitBehaviour;
+
+fn bilinear_function(x: f64, y: f64) -> f64 {
+    1.0 + 2.0 * x - 3.0 * y + 0.5 * x * y
+}
+
+fn grid(interpolation_kind: GridInterpolationKind) -> GridInterpolationTable {
+    let x_keys = vec![0.0, 1.0, 3.0, 4.0];
+    let y_keys = vec![-1.0, 0.0, 2.0];
+    let values = x_keys
+        .iter()
+        .map(|x| y_keys.iter().map(|y| bilinear_function(*x, *y)).collect())
+        .collect();
+    GridInterpolationTable::new(LimitBehaviour::Clamp, x_keys, y_keys, values)
+        .with_interpolation_kind(interpolation_kind)
+}
+
+#[test]
+fn interpolation() {
+    for interpolation_kind in [
+        GridInterpolationKind::Bilinear,
+        GridInterpolationKind::Bicubic,
+    ] {
+        let table = grid(interpolation_kind);
+        for (x, y) in [(0.0, -1.0), (1.0, 0.0), (0.5, 1.0), (2.0, -0.5), (3.7, 1.9)] {
+            let value = table.get(x, y);
+            assert!(
+                (value - bilinear_function(x, y)).abs() < 1e-10,
+                "{interpolation_kind:?} at ({x}, {y}): {value}"
+            );
+        }
+
+        // Outside of the grid, the keys are clamped separately.
+        assert!((table.get(-1.0, 1.0) - bilinear_function(0.0, 1.0)).abs() < 1e-10);
+        assert!((table.get(10.0, 10.0) - bilinear_function(4.0, 2.0)).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn bicubic_is_smooth() {
+    // The square of x is not bilinear, so the interpolations differ between the keys.
+    let x_keys = vec![0.0, 1.0, 2.0, 3.0];
+    let y_keys = vec![0.0, 1.0];
+    let values = x_keys.iter().map(|x| vec![x * x, x * x + 1.0]).collect();
+    let bilinear = GridInterpolationTable::new(LimitBehaviour::Panic, x_keys, y_keys, values);
+    let bicubic = bilinear
+        .clone()
+        .with_interpolation_kind(GridInterpolationKind::Bicubic);
+
+    assert!((bilinear.get(1.5, 0.0) - 2.5).abs() < 1e-10);
+    assert!((bicubic.get(1.5, 0.0) - 2.25).abs() < 0.05);
+
+    // The slope is continuous across a key, and the values stay monotone.
+    let slope = |x: f64| (bicubic.get(x + 1e-6, 0.5) - bicubic.get(x - 1e-6, 0.5)) / 2e-6;
+    assert!((slope(1.0 - 1e-3) - slope(1.0 + 1e-3)).abs() < 0.05);
+    let mut previous = bicubic.get(0.0, 0.5);
+    for i in 1..=300 {
+        let value = bicubic.get(i as f64 * 0.01, 0.5);
+        assert!(value >= previous);
+        previous = value;
+    }
+}
+
+#[test]
+fn invalid() {
+    assert!(matches!(
+        GridInterpolationTable::try_new(
+            LimitBehaviour::Clamp,
+            vec![0.0, 1.0],
+            vec![1.0, 0.0],
+            vec![vec![0.0; 2]; 2],
+        ),
+        Err(Error::UnsortedInterpolationGrid {
+            axis: "y",
+            index: 1
+        })
+    ));
+    assert!(matches!(
+        GridInterpolationTable::try_new(
+            LimitBehaviour::Clamp,
+            vec![0.0, 1.0],
+            vec![0.0, 1.0],
+            vec![vec![0.0; 2], vec![0.0; 3]],
+        ),
+        Err(Error::InvalidInterpolationGridShape {
+            rows: 2,
+            columns: 2
+        })
+    ));
+    assert!(matches!(
+        GridInterpolationTable::try_new(LimitBehaviour::Clamp, vec![], vec![0.0], vec![]),
+        Err(Error::EmptyInterpolationTable)
+    ));
+}
+
+#[test]
+fn limits() {
+    let values = |limit_behaviour| {
+        GridInterpolationTable::new(
+            limit_behaviour,
+            vec![0.0, 1.0, 3.0],
+            vec![0.0, 2.0],
+            vec![0.0, 1.0, 3.0]
+                .into_iter()
+                .map(|x| vec![bilinear_function(x, 0.0), bilinear_function(x, 2.0)])
+                .collect(),
+        )
+        .with_name("test grid")
+    };
+
+    // A bilinear function continues exactly along one axis, and linearly with the slopes at the corner along both.
+    for interpolation_kind in [
+        GridInterpolationKind::Bilinear,
+        GridInterpolationKind::Bicubic,
+    ] {
+        let table = values(LimitBehaviour::Extrapolate).with_interpolation_kind(interpolation_kind);
+        for (x, y) in [(-1.0, 1.0), (4.0, 0.5), (2.0, -1.0), (0.5, 3.0)] {
+            let value = table.get(x, y);
+            assert!(
+                (value - bilinear_function(x, y)).abs() < 1e-10,
+                "{interpolation_kind:?} at ({x}, {y}): {value}"
+            );
+        }
+        let corner =
+            bilinear_function(3.0, 2.0) + 1.0 * (2.0 + 0.5 * 2.0) + 1.0 * (-3.0 + 0.5 * 3.0);
+        assert!((table.get(4.0, 3.0) - corner).abs() < 1e-10);
+    }
+
+    let table = values(LimitBehaviour::Panic);
+    assert!((table.try_get(1.0, 1.0).unwrap() - bilinear_function(1.0, 1.0)).abs() < 1e-10);
+    let error = table.try_get(1.0, 2.5).unwrap_err();
+    assert!(matches!(
+        error,
+        Error::InterpolationKeyOutOfRange { key, minimum, maximum, .. }
+            if key == 2.5 && minimum == 0.0 && maximum == 2.0
+    ));
+    assert!(error.to_string().contains("test grid"), "{error}");
+
+    let table = values(LimitBehaviour::Clamp);
+    assert!(table.try_get(f64::NAN, 1.0).is_err());
+    assert!((table.get(f64::MIN_POSITIVE / 2.0, 0.0) - 1.0).abs() < 1e-10);
+}
@@ -1,62 +1,331 @@
+use std::cmp::Ordering;
+
+use crate::error::{Error, Result};
+
+mod data_file;
+pub mod grid;
+pub mod quantity;
+
+/// What happens when looking up a key outside of the range of a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LimitBehaviour {
+    /// Use the value at the closest end of the table.
     Clamp,
+    /// `get` panics, and `try_get` returns an error.
     Panic,
+    /// Continue linearly with the slope at the closest end of the table.
+    Extrapolate,
 }
 
+/// How values between the keys of a table are interpolated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InterpolationKind {
+    /// Piecewise linear, which has kinks at the keys.
+    #[default]
+    Linear,
+    /// Piecewise cubic Hermite with the derivatives of Fritsch and Carlson (PCHIP).
+    /// This has a continuous first derivative, and does not overshoot, so monotone data stays monotone.
+    MonotoneCubic,
+    /// Natural cubic spline, which has a continuous second derivative.
+    /// Where the data is monotone, the derivatives are limited as proposed by Hyman, so monotone data stays monotone.
+    /// There, the second derivative may be discontinuous.
+    NaturalCubicSpline,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct LinearInterpolationTable {
     table: Vec<(f64, f64)>,
     limit_behaviour: LimitBehaviour,
+    interpolation_kind: InterpolationKind,
+    /// The name used in errors.
+    name: String,
+    /// The derivatives at the keys for cubic interpolation, empty for linear interpolation.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    derivatives: Vec<f64>,
 }
 
 impl LinearInterpolationTable {
+    /// Panics if the table is empty or its keys are not strictly increasing.
     pub fn new(limit_behaviour: LimitBehaviour, table: Vec<(f64, f64)>) -> Self {
-        assert!(!table.is_empty());
-        assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        Self::try_new(limit_behaviour, table).unwrap_or_else(|error| panic!("{error}"))
+    }
 
-        Self {
+    /// Returns an error if the table is empty or its keys are not strictly increasing.
+    pub fn try_new(limit_behaviour: LimitBehaviour, table: Vec<(f64, f64)>) -> Result<Self> {
+        if table.is_empty() {
+            return Err(Error::EmptyInterpolationTable);
+        }
+        if let Some(index) = table
+            .windows(2)
+            .position(|pair| pair[0].0.partial_cmp(&pair[1].0) != Some(Ordering::Less))
+        {
+            return Err(Error::UnsortedInterpolationTable { index: index + 1 });
+        }
+
+        Ok(Self {
             table,
             limit_behaviour,
+            interpolation_kind: InterpolationKind::Linear,
+            name: String::from("unnamed"),
+            derivatives: Vec::new(),
+        })
+    }
+
+    /// Set the name of the table that is shown in errors.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..self
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn with_interpolation_kind(self, interpolation_kind: InterpolationKind) -> Self {
+        let derivatives = match interpolation_kind {
+            InterpolationKind::Linear => Vec::new(),
+            InterpolationKind::MonotoneCubic => monotone_cubic_derivatives(&self.table),
+            InterpolationKind::NaturalCubicSpline => natural_cubic_spline_derivatives(&self.table),
+        };
+        Self {
+            interpolation_kind,
+            derivatives,
+            ..self
         }
     }
 
+    pub fn interpolation_kind(&self) -> InterpolationKind {
+        self.interpolation_kind
+    }
+
+    /// Panics if the key is NaN, or outside of the table with [`LimitBehaviour::Panic`].
     pub fn get(&self, x: f64) -> f64 {
-        assert!(x.is_normal() || x == 0.0 || x == -0.0);
+        self.try_get(x).unwrap_or_else(|error| panic!("{error}"))
+    }
 
-        match self.limit_behaviour {
-            LimitBehaviour::Clamp => {
-                if x < self.table.first().unwrap().0 {
-                    return self.table.first().unwrap().1;
-                }
-                if x > self.table.last().unwrap().0 {
-                    return self.table.last().unwrap().1;
+    /// Returns an error if the key is NaN, or outside of the table with [`LimitBehaviour::Panic`].
+    pub fn try_get(&self, x: f64) -> Result<f64> {
+        let (first_key, first_value) = *self.table.first().unwrap();
+        let (last_key, last_value) = *self.table.last().unwrap();
+        let out_of_range = || Error::InterpolationKeyOutOfRange {
+            table: self.name.clone(),
+            key: x,
+            minimum: first_key,
+            maximum: last_key,
+        };
+        if x.is_nan() {
+            return Err(out_of_range());
+        }
+
+        if x < first_key || x > last_key {
+            return match self.limit_behaviour {
+                LimitBehaviour::Clamp if x < first_key => Ok(first_value),
+                LimitBehaviour::Clamp => Ok(last_value),
+                LimitBehaviour::Panic => Err(out_of_range()),
+                LimitBehaviour::Extrapolate => {
+                    let (slope_at_start, slope_at_end) = self.end_slopes();
+                    if x < first_key {
+                        Ok(first_value + (x - first_key) * slope_at_start)
+                    } else {
+                        Ok(last_value + (x - last_key) * slope_at_end)
+                    }
                 }
-            }
-            LimitBehaviour::Panic => {
-                assert!(x >= self.table.first().unwrap().0);
-                assert!(x <= self.table.last().unwrap().0);
-            }
+            };
         }
 
-        match self
+        let index = match self
             .table
             .binary_search_by(|pair| pair.0.partial_cmp(&x).unwrap())
         {
-            Ok(index) => self.table[index].1,
-            Err(index) => {
-                assert!(index > 0);
-                assert!(index < self.table.len());
-                let (key1, value1) = self.table[index - 1];
-                let (key2, value2) = self.table[index];
-
-                ((key2 - x) * value1 + (x - key1) * value2) / (key2 - key1)
+            Ok(index) => return Ok(self.table[index].1),
+            Err(index) => index,
+        };
+        let (key1, value1) = self.table[index - 1];
+        let (key2, value2) = self.table[index];
+
+        if self.interpolation_kind == InterpolationKind::Linear {
+            return Ok(((key2 - x) * value1 + (x - key1) * value2) / (key2 - key1));
+        }
+
+        // Cubic Hermite interpolation between the two keys.
+        let width = key2 - key1;
+        let t = (x - key1) / width;
+        let derivative1 = self.derivatives[index - 1] * width;
+        let derivative2 = self.derivatives[index] * width;
+        Ok(value1
+            + t * t * (3.0 - 2.0 * t) * (value2 - value1)
+            + t * (1.0 - t) * (1.0 - t) * derivative1
+            + t * t * (t - 1.0) * derivative2)
+    }
+
+    /// The slopes at the first and at the last key, which are zero for a table with a single entry.
+    fn end_slopes(&self) -> (f64, f64) {
+        match self.interpolation_kind {
+            _ if self.table.len() == 1 => (0.0, 0.0),
+            InterpolationKind::Linear => {
+                let secant =
+                    |pair: &[(f64, f64)]| (pair[1].1 - pair[0].1) / (pair[1].0 - pair[0].0);
+                (
+                    secant(&self.table[..2]),
+                    secant(&self.table[self.table.len() - 2..]),
+                )
+            }
+            InterpolationKind::MonotoneCubic | InterpolationKind::NaturalCubicSpline => {
+                (self.derivatives[0], *self.derivatives.last().unwrap())
             }
         }
     }
 }
 
+/// The slopes of the segments between the keys.
+fn secants(table: &[(f64, f64)]) -> Vec<f64> {
+    table
+        .windows(2)
+        .map(|pair| (pair[1].1 - pair[0].1) / (pair[1].0 - pair[0].0))
+        .collect()
+}
+
+/// The derivatives of Fritsch and Carlson, using the weighted harmonic mean of the adjacent secants as by Fritsch and Butland.
+/// The derivative is zero at local extrema, and the end derivatives use a shape-preserving three-point formula.
+fn monotone_cubic_derivatives(table: &[(f64, f64)]) -> Vec<f64> {
+    let secants = secants(table);
+    match secants.len() {
+        0 => return vec![0.0],
+        1 => return vec![secants[0]; 2],
+        _ => {}
+    }
+    let widths: Vec<f64> = table.windows(2).map(|pair| pair[1].0 - pair[0].0).collect();
+
+    let mut derivatives = vec![0.0; table.len()];
+    for i in 1..table.len() - 1 {
+        let (secant1, secant2) = (secants[i - 1], secants[i]);
+        if secant1 * secant2 > 0.0 {
+            let weight1 = 2.0 * widths[i] + widths[i - 1];
+            let weight2 = widths[i] + 2.0 * widths[i - 1];
+            derivatives[i] = (weight1 + weight2) / (weight1 / secant1 + weight2 / secant2);
+        }
+    }
+
+    let end_derivative = |width1: f64, width2: f64, secant1: f64, secant2: f64| {
+        let derivative = ((2.0 * width1 + width2) * secant1 - width1 * secant2) / (width1 + width2);
+        if derivative * secant1 <= 0.0 {
+            0.0
+        } else if secant1 * secant2 <= 0.0 && derivative.abs() > 3.0 * secant1.abs() {
+            3.0 * secant1
+        } else {
+            derivative
+        }
+    };
+    let last = secants.len() - 1;
+    derivatives[0] = end_derivative(widths[0], widths[1], secants[0], secants[1]);
+    derivatives[last + 1] = end_derivative(
+        widths[last],
+        widths[last - 1],
+        secants[last],
+        secants[last - 1],
+    );
+    derivatives
+}
+
+/// The derivatives of the natural cubic spline, limited by the filter of Hyman where the data is monotone.
+fn natural_cubic_spline_derivatives(table: &[(f64, f64)]) -> Vec<f64> {
+    let secants = secants(table);
+    if secants.is_empty() {
+        return vec![0.0];
+    }
+    let widths: Vec<f64> = table.windows(2).map(|pair| pair[1].0 - pair[0].0).collect();
+
+    // Solve the tridiagonal system for the second derivatives with the Thomas algorithm.
+    // They are zero at both ends.
+    let n = table.len();
+    let mut second_derivatives = vec![0.0; n];
+    let mut upper = vec![0.0; n];
+    let mut right_hand_side = vec![0.0; n];
+    for i in 1..n - 1 {
+        let lower = widths[i - 1];
+        let diagonal = 2.0 * (widths[i - 1] + widths[i]) - lower * upper[i - 1];
+        upper[i] = widths[i] / diagonal;
+        right_hand_side[i] =
+            (6.0 * (secants[i] - secants[i - 1]) - lower * right_hand_side[i - 1]) / diagonal;
+    }
+    for i in (1..n - 1).rev() {
+        second_derivatives[i] = right_hand_side[i] - upper[i] * second_derivatives[i + 1];
+    }
+
+    let mut derivatives: Vec<f64> = (0..n - 1)
+        .map(|i| {
+            secants[i] - widths[i] * (2.0 * second_derivatives[i] + second_derivatives[i + 1]) / 6.0
+        })
+        .collect();
+    derivatives.push(
+        secants[n - 2]
+            + widths[n - 2] * (second_derivatives[n - 2] + 2.0 * second_derivatives[n - 1]) / 6.0,
+    );
+
+    // Keep the derivatives between zero and three times the adjacent secants, which is sufficient for monotonicity.
+    let limit = |derivative: f64, secant1: f64, secant2: f64| {
+        if secant1 * secant2 > 0.0 {
+            let sign = secant1.signum();
+            sign * (sign * derivative)
+                .max(0.0)
+                .min(3.0 * secant1.abs().min(secant2.abs()))
+        } else if secant1 == 0.0 || secant2 == 0.0 {
+            0.0
+        } else {
+            // A local extremum of the data.
+            derivative
+        }
+    };
+    derivatives[0] = limit(derivatives[0], secants[0], secants[0]);
+    for i in 1..n - 1 {
+        derivatives[i] = limit(derivatives[i], secants[i - 1], secants[i]);
+    }
+    derivatives[n - 1] = limit(derivatives[n - 1], secants[n - 2], secants[n - 2]);
+    derivatives
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LinearInterpolationTable {
+    /// Deserialise a table, checking it like [`LinearInterpolationTable::try_new`].
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Unchecked {
+            table: Vec<(f64, f64)>,
+            limit_behaviour: LimitBehaviour,
+            #[serde(default)]
+            interpolation_kind: InterpolationKind,
+            name: Option<String>,
+        }
+
+        let Unchecked {
+            table,
+            limit_behaviour,
+            interpolation_kind,
+            name,
+        } = Unchecked::deserialize(deserializer)?;
+        Self::try_new(limit_behaviour, table)
+            .map(|table| {
+                let table = table.with_interpolation_kind(interpolation_kind);
+                match name {
+                    Some(name) => table.with_name(name),
+                    None => table,
+                }
+            })
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::interpolation_table::LimitBehaviour;
+    use crate::error::Error;
+    use crate::interpolation_table::{InterpolationKind, LimitBehaviour};
 
     use super::LinearInterpolationTable;
 
@@ -77,4 +346,128 @@ mod tests {
         assert!((interpolation_table.get(-1.0) - 1.0).abs() < 1e-10);
         assert!((interpolation_table.get(5.0) - 0.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn cubic() {
+        for interpolation_kind in [
+            InterpolationKind::MonotoneCubic,
+            InterpolationKind::NaturalCubicSpline,
+        ] {
+            // Linear data stays linear.
+            let interpolation_table = LinearInterpolationTable::new(
+                LimitBehaviour::Clamp,
+                vec![(0.0, 1.0), (1.0, 3.0), (3.0, 7.0), (4.0, 9.0)],
+            )
+            .with_interpolation_kind(interpolation_kind);
+            assert!((interpolation_table.get(0.5) - 2.0).abs() < 1e-10);
+            assert!((interpolation_table.get(2.0) - 5.0).abs() < 1e-10);
+            assert!((interpolation_table.get(3.0) - 7.0).abs() < 1e-10);
+            assert!((interpolation_table.get(5.0) - 9.0).abs() < 1e-10);
+        }
+
+        // The natural spline through these points has the second derivative -3 at the middle.
+        let interpolation_table = LinearInterpolationTable::new(
+            LimitBehaviour::Clamp,
+            vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)],
+        )
+        .with_interpolation_kind(InterpolationKind::NaturalCubicSpline);
+        assert!((interpolation_table.get(0.5) - 0.6875).abs() < 1e-10);
+        assert!((interpolation_table.get(1.5) - 0.6875).abs() < 1e-10);
+
+        // PCHIP keeps the extremum at the key.
+        let interpolation_table = LinearInterpolationTable::new(
+            LimitBehaviour::Clamp,
+            vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)],
+        )
+        .with_interpolation_kind(InterpolationKind::MonotoneCubic);
+        assert!(interpolation_table.get(0.9) < 1.0);
+        assert!(interpolation_table.get(1.1) < 1.0);
+    }
+
+    #[test]
+    fn cubic_stays_monotone() {
+        let table = vec![
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (1.5, 1.0),
+            (3.0, 1.0),
+            (3.1, 5.0),
+            (10.0, 5.5),
+        ];
+        for interpolation_kind in [
+            InterpolationKind::MonotoneCubic,
+            InterpolationKind::NaturalCubicSpline,
+        ] {
+            let interpolation_table =
+                LinearInterpolationTable::new(LimitBehaviour::Clamp, table.clone())
+                    .with_interpolation_kind(interpolation_kind);
+            let mut previous = interpolation_table.get(0.0);
+            for i in 1..=10000 {
+                let value = interpolation_table.get(i as f64 * 1e-3);
+                assert!(
+                    value >= previous,
+                    "{interpolation_kind:?} at {}",
+                    i as f64 * 1e-3
+                );
+                previous = value;
+            }
+            for (key, value) in &table {
+                assert!((interpolation_table.get(*key) - value).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn extrapolate() {
+        let table = vec![(0.0, 1.0), (2.0, 2.0), (3.0, 0.0)];
+        let interpolation_table =
+            LinearInterpolationTable::new(LimitBehaviour::Extrapolate, table.clone());
+        assert!((interpolation_table.get(1.0) - 1.5).abs() < 1e-10);
+        assert!((interpolation_table.get(-2.0) - 0.0).abs() < 1e-10);
+        assert!((interpolation_table.get(4.0) - -2.0).abs() < 1e-10);
+
+        // Cubic tables continue with the derivatives at the ends.
+        let interpolation_table = LinearInterpolationTable::new(
+            LimitBehaviour::Extrapolate,
+            vec![(0.0, 1.0), (1.0, 3.0), (3.0, 7.0)],
+        )
+        .with_interpolation_kind(InterpolationKind::MonotoneCubic);
+        assert!((interpolation_table.get(-1.0) - -1.0).abs() < 1e-10);
+        assert!((interpolation_table.get(5.0) - 11.0).abs() < 1e-10);
+
+        let single = LinearInterpolationTable::new(LimitBehaviour::Extrapolate, vec![(1.0, 4.0)]);
+        assert_eq!(single.get(-3.0), 4.0);
+    }
+
+    #[test]
+    fn try_get() {
+        let interpolation_table =
+            LinearInterpolationTable::new(LimitBehaviour::Panic, vec![(0.0, 1.0), (2.0, 2.0)])
+                .with_name("test table");
+        assert_eq!(interpolation_table.name(), "test table");
+        assert!((interpolation_table.try_get(1.0).unwrap() - 1.5).abs() < 1e-10);
+
+        let error = interpolation_table.try_get(2.5).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::InterpolationKeyOutOfRange { key, minimum, maximum, .. }
+                if key == 2.5 && minimum == 0.0 && maximum == 2.0
+        ));
+        assert!(error.to_string().contains("test table"), "{error}");
+        assert!(error.to_string().contains("2.5"), "{error}");
+
+        // NaN is never valid, but subnormal keys are.
+        let clamped =
+            LinearInterpolationTable::new(LimitBehaviour::Clamp, vec![(0.0, 1.0), (2.0, 2.0)]);
+        assert!(clamped.try_get(f64::NAN).is_err());
+        assert!((clamped.get(f64::MIN_POSITIVE / 2.0) - 1.0).abs() < 1e-10);
+        assert!((clamped.get(-f64::MIN_POSITIVE / 2.0) - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the range")]
+    fn panic() {
+        LinearInterpolationTable::new(LimitBehaviour::Panic, vec![(0.0, 1.0), (2.0, 2.0)])
+            .get(-1.0);
+    }
 }
@@ -0,0 +1,225 @@
+//! Interpolation tables and grids with keys and values of physical quantities.
+//!
+//! The tables store plain numbers in the base units of the quantities, so the units of the entries only matter when building them.
+//! Linear and cubic interpolation commute with the affine conversions between units, e.g. from Celsius to Kelvin.
+
+use std::marker::PhantomData;
+
+use uom::si::{Dimension, Quantity, Units};
+
+use crate::error::Result;
+use crate::interpolation_table::grid::{GridInterpolationKind, GridInterpolationTable};
+use crate::interpolation_table::{InterpolationKind, LimitBehaviour, LinearInterpolationTable};
+
+#[cfg(test)]
+mod tests;
+
+/// A quantity that can be used as key or value of a [`QuantityTable`] or a [`QuantityGrid`].
+pub trait TableQuantity: Copy {
+    /// The value in the base unit.
+    fn to_base(self) -> f64;
+
+    /// The quantity with the given value in the base unit.
+    fn from_base(value: f64) -> Self;
+}
+
+impl<D, U> TableQuantity for Quantity<D, U, f64>
+where
+    D: Dimension + ?Sized,
+    U: Units<f64> + ?Sized,
+{
+    fn to_base(self) -> f64 {
+        self.value
+    }
+
+    fn from_base(value: f64) -> Self {
+        Quantity {
+            dimension: PhantomData,
+            units: PhantomData,
+            value,
+        }
+    }
+}
+
+/// A [`LinearInterpolationTable`] from one quantity to another.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent, bound = "")
+)]
+pub struct QuantityTable<X, Y> {
+    table: LinearInterpolationTable,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    quantities: PhantomData<fn(X) -> Y>,
+}
+
+impl<X: TableQuantity, Y: TableQuantity> QuantityTable<X, Y> {
+    /// Panics if a table with these entries cannot be created, see [`QuantityTable::try_new`].
+    pub fn new(limit_behaviour: LimitBehaviour, table: Vec<(X, Y)>) -> Self {
+        Self::try_new(limit_behaviour, table).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Returns an error if there are no entries, or the keys are not strictly increasing.
+    pub fn try_new(limit_behaviour: LimitBehaviour, table: Vec<(X, Y)>) -> Result<Self> {
+        let table = table
+            .into_iter()
+            .map(|(key, value)| (key.to_base(), value.to_base()))
+            .collect();
+        LinearInterpolationTable::try_new(limit_behaviour, table).map(|table| Self {
+            table,
+            quantities: PhantomData,
+        })
+    }
+
+    /// Build a table from plain numbers, converting the keys and values with the given unit constructors,
+    /// e.g. `ThermodynamicTemperature::new::<degree_celsius>`.
+    /// Panics like [`QuantityTable::new`].
+    pub fn from_units(
+        limit_behaviour: LimitBehaviour,
+        key_unit: impl Fn(f64) -> X,
+        value_unit: impl Fn(f64) -> Y,
+        table: impl IntoIterator<Item = (f64, f64)>,
+    ) -> Self {
+        Self::new(
+            limit_behaviour,
+            table
+                .into_iter()
+                .map(|(key, value)| (key_unit(key), value_unit(value)))
+                .collect(),
+        )
+    }
+
+    pub fn with_interpolation_kind(self, interpolation_kind: InterpolationKind) -> Self {
+        Self {
+            table: self.table.with_interpolation_kind(interpolation_kind),
+            ..self
+        }
+    }
+
+    /// Set the name of the table that is shown in errors.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        Self {
+            table: self.table.with_name(name),
+            ..self
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.table.name()
+    }
+
+    /// Panics like [`LinearInterpolationTable::get`].
+    pub fn get(&self, x: X) -> Y {
+        Y::from_base(self.table.get(x.to_base()))
+    }
+
+    /// Returns an error like [`LinearInterpolationTable::try_get`].
+    /// The key and range in the error are in the base unit of the key.
+    pub fn try_get(&self, x: X) -> Result<Y> {
+        self.table.try_get(x.to_base()).map(Y::from_base)
+    }
+}
+
+/// A [`GridInterpolationTable`] from two quantities to a third.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent, bound = "")
+)]
+pub struct QuantityGrid<X, Y, Z> {
+    table: GridInterpolationTable,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    quantities: PhantomData<fn(X, Y) -> Z>,
+}
+
+impl<X: TableQuantity, Y: TableQuantity, Z: TableQuantity> QuantityGrid<X, Y, Z> {
+    /// Panics if a grid with these keys and values cannot be created, see [`QuantityGrid::try_new`].
+    pub fn new(
+        limit_behaviour: LimitBehaviour,
+        x_keys: Vec<X>,
+        y_keys: Vec<Y>,
+        values: Vec<Vec<Z>>,
+    ) -> Self {
+        Self::try_new(limit_behaviour, x_keys, y_keys, values)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Returns an error like [`GridInterpolationTable::try_new`].
+    pub fn try_new(
+        limit_behaviour: LimitBehaviour,
+        x_keys: Vec<X>,
+        y_keys: Vec<Y>,
+        values: Vec<Vec<Z>>,
+    ) -> Result<Self> {
+        GridInterpolationTable::try_new(
+            limit_behaviour,
+            x_keys.into_iter().map(X::to_base).collect(),
+            y_keys.into_iter().map(Y::to_base).collect(),
+            values
+                .into_iter()
+                .map(|row| row.into_iter().map(Z::to_base).collect())
+                .collect(),
+        )
+        .map(|table| Self {
+            table,
+            quantities: PhantomData,
+        })
+    }
+
+    /// Build a grid from plain numbers, converting the keys and values with the given unit constructors,
+    /// e.g. `ThermodynamicTemperature::new::<degree_celsius>`.
+    /// Panics like [`QuantityGrid::new`].
+    pub fn from_units(
+        limit_behaviour: LimitBehaviour,
+        x_unit: impl Fn(f64) -> X,
+        y_unit: impl Fn(f64) -> Y,
+        value_unit: impl Fn(f64) -> Z,
+        x_keys: impl IntoIterator<Item = f64>,
+        y_keys: impl IntoIterator<Item = f64>,
+        values: impl IntoIterator<Item = Vec<f64>>,
+    ) -> Self {
+        Self::new(
+            limit_behaviour,
+            x_keys.into_iter().map(x_unit).collect(),
+            y_keys.into_iter().map(y_unit).collect(),
+            values
+                .into_iter()
+                .map(|row| row.into_iter().map(&value_unit).collect())
+                .collect(),
+        )
+    }
+
+    pub fn with_interpolation_kind(self, interpolation_kind: GridInterpolationKind) -> Self {
+        Self {
+            table: self.table.with_interpolation_kind(interpolation_kind),
+            ..self
+        }
+    }
+
+    /// Set the name of the grid that is shown in errors.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        Self {
+            table: self.table.with_name(name),
+            ..self
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.table.name()
+    }
+
+    /// Panics like [`GridInterpolationTable::get`].
+    pub fn get(&self, x: X, y: Y) -> Z {
+        Z::from_base(self.table.get(x.to_base(), y.to_base()))
+    }
+
+    /// Returns an error like [`GridInterpolationTable::try_get`].
+    /// The key and range in the error are in the base unit of the key.
+    pub fn try_get(&self, x: X, y: Y) -> Result<Z> {
+        self.table
+            .try_get(x.to_base(), y.to_base())
+            .map(Z::from_base)
+    }
+}
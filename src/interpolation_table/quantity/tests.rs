@@ -0,0 +1,126 @@
+use uom::si::{
+    f64::{MassDensity, Pressure, ThermodynamicTemperature},
+    mass_density::{gram_per_cubic_centimeter, kilogram_per_cubic_meter},
+    pressure::{bar, millibar, pascal},
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+use super::{QuantityGrid, QuantityTable};
+use crate::error::Error;
+use crate::interpolation_table::grid::GridInterpolationKind;
+use crate::interpolation_table::{InterpolationKind, LimitBehaviour};
+
+fn density_table() -> QuantityTable<ThermodynamicTemperature, MassDensity> {
+    QuantityTable::from_units(
+        LimitBehaviour::Panic,
+        ThermodynamicTemperature::new::<degree_celsius>,
+        MassDensity::new::<gram_per_cubic_centimeter>,
+        vec![(0.0, 1.0), (100.0, 0.9), (200.0, 0.8)],
+    )
+    .with_name("test density")
+}
+
+#[test]
+fn units() {
+    let table = density_table();
+
+    // The keys and values are converted, so any unit can be used for lookups.
+    let density = table.get(ThermodynamicTemperature::new::<kelvin>(323.15));
+    assert!((density.get::<kilogram_per_cubic_meter>() - 950.0).abs() < 1e-9);
+    let density = table.get(ThermodynamicTemperature::new::<degree_celsius>(150.0));
+    assert!((density.get::<gram_per_cubic_centimeter>() - 0.85).abs() < 1e-12);
+
+    let typed = QuantityTable::new(
+        LimitBehaviour::Clamp,
+        vec![
+            (
+                Pressure::new::<bar>(1.0),
+                ThermodynamicTemperature::new::<degree_celsius>(99.6),
+            ),
+            (
+                Pressure::new::<bar>(2.0),
+                ThermodynamicTemperature::new::<degree_celsius>(120.2),
+            ),
+        ],
+    );
+    let temperature = typed.get(Pressure::new::<millibar>(1500.0));
+    assert!((temperature.get::<degree_celsius>() - 109.9).abs() < 1e-9);
+    let temperature = typed.get(Pressure::new::<pascal>(1.0e6));
+    assert!((temperature.get::<degree_celsius>() - 120.2).abs() < 1e-9);
+}
+
+#[test]
+fn interpolation_kind() {
+    // Cubic interpolation is invariant under the conversion from Celsius to Kelvin.
+    let table = density_table().with_interpolation_kind(InterpolationKind::MonotoneCubic);
+    let density = table.get(ThermodynamicTemperature::new::<degree_celsius>(50.0));
+    assert!((density.get::<gram_per_cubic_centimeter>() - 0.95).abs() < 1e-12);
+}
+
+#[test]
+fn errors() {
+    let table = density_table();
+    assert_eq!(table.name(), "test density");
+
+    let error = table
+        .try_get(ThermodynamicTemperature::new::<degree_celsius>(250.0))
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        Error::InterpolationKeyOutOfRange { key, maximum, .. }
+            if (key - 523.15).abs() < 1e-9 && (maximum - 473.15).abs() < 1e-9
+    ));
+    assert!(error.to_string().contains("test density"), "{error}");
+
+    assert!(matches!(
+        QuantityTable::<Pressure, MassDensity>::try_new(LimitBehaviour::Clamp, vec![]),
+        Err(Error::EmptyInterpolationTable)
+    ));
+}
+
+#[test]
+fn grid() {
+    let grid: QuantityGrid<ThermodynamicTemperature, Pressure, MassDensity> =
+        QuantityGrid::from_units(
+            LimitBehaviour::Panic,
+            ThermodynamicTemperature::new::<degree_celsius>,
+            Pressure::new::<bar>,
+            MassDensity::new::<gram_per_cubic_centimeter>,
+            [0.0, 100.0],
+            [1.0, 101.0],
+            [vec![1.0, 1.1], vec![0.9, 1.0]],
+        )
+        .with_name("test grid");
+    assert_eq!(grid.name(), "test grid");
+
+    let density = grid.get(
+        ThermodynamicTemperature::new::<kelvin>(323.15),
+        Pressure::new::<pascal>(51.0e5),
+    );
+    assert!((density.get::<kilogram_per_cubic_meter>() - 1000.0).abs() < 1e-9);
+    let density = grid
+        .clone()
+        .with_interpolation_kind(GridInterpolationKind::Bicubic)
+        .get(
+            ThermodynamicTemperature::new::<degree_celsius>(0.0),
+            Pressure::new::<millibar>(51_000.0),
+        );
+    assert!((density.get::<gram_per_cubic_centimeter>() - 1.05).abs() < 1e-12);
+
+    assert!(matches!(
+        grid.try_get(
+            ThermodynamicTemperature::new::<degree_celsius>(50.0),
+            Pressure::new::<bar>(200.0),
+        ),
+        Err(Error::InterpolationKeyOutOfRange { key, .. }) if (key - 200.0e5).abs() < 1e-6
+    ));
+    assert!(matches!(
+        QuantityGrid::<ThermodynamicTemperature, Pressure, MassDensity>::try_new(
+            LimitBehaviour::Clamp,
+            vec![ThermodynamicTemperature::new::<degree_celsius>(0.0)],
+            vec![Pressure::new::<bar>(1.0)],
+            vec![],
+        ),
+        Err(Error::InvalidInterpolationGridShape { .. })
+    ));
+}
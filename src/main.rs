@@ -30,6 +30,8 @@ pub mod container;
 pub mod electrical_grid;
 pub mod error;
 pub mod interpolation_table;
+#[cfg(feature = "serde")]
+pub mod snapshot;
 pub mod substance;
 pub mod synchronous_machine;
 pub mod type_parameterisation;
@@ -113,7 +115,7 @@ fn container_sim() {
             Mass::new::<megagram>(49.85),
             ThermodynamicTemperature::new::<degree_celsius>(360.0),
         ),
-        Water::new(
+        Water::new_steam(
             Mass::new::<kilogram>(275.0),
             ThermodynamicTemperature::new::<degree_celsius>(350.0),
         ),
@@ -121,8 +123,8 @@ fn container_sim() {
 
     println!("Iteration  0: {container}");
     for iteration in 1..=10 {
-        container.evaporate_condensate();
-        container.convect(Time::new::<second>(0.1));
+        container.evaporate_condensate().unwrap();
+        container.convect(Time::new::<second>(0.1)).unwrap();
         println!("Iteration {iteration:2}: {container}");
     }
 }
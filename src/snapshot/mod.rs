@@ -0,0 +1,50 @@
+//! Checkpoints of the simulation state, to restore a plant mid-transient or to load initial conditions from data files.
+//!
+//! This module is only available with the `serde` feature.
+//! All quantities are stored as plain numbers in SI base units, e.g. kelvin for temperatures and J/kg for specific enthalpies.
+//! The field names carry the unit as a suffix, e.g. `mass_kg`.
+//! Ratios such as concentrations are stored as plain fractions, e.g. `boron_concentration_kg_per_kg`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+
+#[cfg(test)]
+mod tests;
+
+/// The version of the snapshot format written by this version of the crate.
+/// It is increased whenever the serialised form of a type changes incompatibly.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Some simulation state together with the version of the format it was written in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot<T> {
+    format_version: u32,
+    state: T,
+}
+
+impl<T> Snapshot<T> {
+    /// Wrap the state for serialisation with the current format version.
+    pub fn new(state: T) -> Self {
+        Self {
+            format_version: FORMAT_VERSION,
+            state,
+        }
+    }
+
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+
+    /// Unwrap the state, checking that it was written in the current format version.
+    pub fn into_state(self) -> Result<T> {
+        if self.format_version == FORMAT_VERSION {
+            Ok(self.state)
+        } else {
+            Err(Error::UnsupportedFormatVersion {
+                version: self.format_version,
+                supported: FORMAT_VERSION,
+            })
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use uom::si::{
+    angle::radian,
+    angular_velocity::radian_per_second,
+    area::square_meter,
+    f64::{
+        Angle, AngularVelocity, Area, MagneticFlux, Mass, MomentOfInertia, Power, Pressure, Ratio,
+        SpecificRadioactivity, ThermodynamicTemperature, Time, Volume,
+    },
+    magnetic_flux::weber,
+    mass::kilogram,
+    moment_of_inertia::kilogram_square_meter,
+    power::watt,
+    pressure::pascal,
+    ratio::part_per_million,
+    specific_radioactivity::becquerel_per_kilogram,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    time::second,
+    volume::cubic_meter,
+};
+
+use super::{Snapshot, FORMAT_VERSION};
+use crate::container::WaterContainer;
+use crate::error::Error;
+use crate::interpolation_table::grid::{GridInterpolationKind, GridInterpolationTable};
+use crate::interpolation_table::{InterpolationKind, LimitBehaviour, LinearInterpolationTable};
+use crate::substance::liquid_metal::{LiquidMetal, LiquidMetalCoolant};
+use crate::substance::non_condensable_gas::{GasMixture, NonCondensableGas};
+use crate::substance::water::{
+    chemistry::Chemistry,
+    equation_of_state::SteamEquationOfState,
+    isotopes::{Isotope, IsotopeInventory},
+    Water,
+};
+use crate::substance::Substance;
+use crate::synchronous_machine::SynchronousMachine;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= 1e-12 * expected.abs(),
+        "{actual} != {expected}"
+    );
+}
+
+#[test]
+fn water_container() {
+    let mut container = WaterContainer::new(
+        Volume::new::<cubic_meter>(10.0),
+        Area::new::<square_meter>(5.0),
+        Water::new(
+            Mass::new::<kilogram>(5000.0),
+            ThermodynamicTemperature::new::<degree_celsius>(250.0),
+        )
+        .with_boron_concentration(Ratio::new::<part_per_million>(1000.0)),
+        Water::new_steam(
+            Mass::new::<kilogram>(10.0),
+            ThermodynamicTemperature::new::<degree_celsius>(250.0),
+        )
+        .with_equation_of_state(SteamEquationOfState::PengRobinson),
+    )
+    .with_gases(GasMixture::new(
+        NonCondensableGas::Nitrogen,
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(250.0),
+    ));
+    container.evaporate_condensate().unwrap();
+    container.convect(Time::new::<second>(1.0)).unwrap();
+
+    let json = serde_json::to_string(&Snapshot::new(container.clone())).unwrap();
+    let restored: WaterContainer = serde_json::from_str::<Snapshot<WaterContainer>>(&json)
+        .unwrap()
+        .into_state()
+        .unwrap();
+
+    assert_close(
+        restored.pressure().get::<pascal>(),
+        container.pressure().get::<pascal>(),
+    );
+    assert_close(
+        restored.liquid().temperature().get::<kelvin>(),
+        container.liquid().temperature().get::<kelvin>(),
+    );
+    assert_close(
+        restored.vapour().mass().get::<kilogram>(),
+        container.vapour().mass().get::<kilogram>(),
+    );
+    assert_close(
+        restored.boron_concentration().get::<part_per_million>(),
+        container.boron_concentration().get::<part_per_million>(),
+    );
+    assert_eq!(
+        restored.vapour().equation_of_state(),
+        SteamEquationOfState::PengRobinson
+    );
+    assert_eq!(restored.gases().total_mass(), Mass::new::<kilogram>(1.0));
+}
+
+#[test]
+fn si_units() {
+    let water = Water::new(
+        Mass::new::<kilogram>(2.0),
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+    );
+    let value = serde_json::to_value(Snapshot::new(water)).unwrap();
+    assert_eq!(value["format_version"], FORMAT_VERSION);
+    assert_eq!(value["state"]["mass_kg"], 2.0);
+    assert!(
+        value["state"]["specific_enthalpy_j_per_kg"]
+            .as_f64()
+            .unwrap()
+            > 80_000.0
+    );
+    assert_eq!(value["state"]["phase"], "Liquid");
+}
+
+#[test]
+fn dissolved_substances() {
+    let water = Water::new(
+        Mass::new::<kilogram>(2.0),
+        ThermodynamicTemperature::new::<degree_celsius>(290.0),
+    )
+    .with_boron_concentration(Ratio::new::<part_per_million>(1000.0))
+    .with_chemistry(Chemistry {
+        lithium: Ratio::new::<part_per_million>(2.0),
+        ..Chemistry::zero()
+    })
+    .with_isotopes(IsotopeInventory::zero().with(
+        Isotope::Caesium137,
+        SpecificRadioactivity::new::<becquerel_per_kilogram>(1e3),
+    ));
+    let value = serde_json::to_value(water).unwrap();
+    assert_close(
+        value["boron_concentration_kg_per_kg"].as_f64().unwrap(),
+        1e-3,
+    );
+    assert_close(
+        value["chemistry"]["lithium_kg_per_kg"].as_f64().unwrap(),
+        2e-6,
+    );
+    assert_eq!(value["chemistry"]["dissolved_oxygen_kg_per_kg"], 0.0);
+    assert!(value["isotopes"]["specific_activities_bq_per_kg"].is_array());
+
+    let restored: Water = serde_json::from_value(value).unwrap();
+    assert_close(
+        restored.boron_concentration().get::<part_per_million>(),
+        1000.0,
+    );
+    assert_eq!(restored.chemistry(), water.chemistry());
+    assert_close(
+        restored
+            .isotopes()
+            .specific_activity(Isotope::Caesium137)
+            .get::<becquerel_per_kilogram>(),
+        1e3,
+    );
+}
+
+#[test]
+fn liquid_metal() {
+    let sodium = LiquidMetal::new(
+        LiquidMetalCoolant::Sodium,
+        Mass::new::<kilogram>(2.0),
+        ThermodynamicTemperature::new::<degree_celsius>(400.0),
+    )
+    .unwrap();
+    let json = serde_json::to_string(&sodium).unwrap();
+    let restored: LiquidMetal = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.coolant(), LiquidMetalCoolant::Sodium);
+    assert_eq!(restored.mass(), sodium.mass());
+    assert_eq!(restored.temperature(), sodium.temperature());
+
+    // Sodium boils at about 1156 K.
+    let boiling = r#"{"coolant": "Sodium", "mass_kg": 2.0, "temperature_k": 1500.0}"#;
+    let error = serde_json::from_str::<LiquidMetal>(boiling).unwrap_err();
+    assert!(error.to_string().contains("boil"), "{error}");
+}
+
+#[test]
+fn synchronous_machine() {
+    let machine = SynchronousMachine::new(
+        MomentOfInertia::new::<kilogram_square_meter>(1.0e4),
+        Angle::new::<radian>(0.5),
+        AngularVelocity::new::<radian_per_second>(314.0),
+        Power::new::<watt>(1.0e9),
+        MagneticFlux::new::<weber>(10.0),
+        Power::new::<watt>(1.0e6),
+    );
+    let json = serde_json::to_string(&machine).unwrap();
+    assert!(json.contains("\"angular_velocity_rad_per_s\":314.0"));
+
+    let restored: SynchronousMachine = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.angular_position(), machine.angular_position());
+    assert_eq!(restored.angular_velocity(), machine.angular_velocity());
+    assert_eq!(restored.power_rating(), machine.power_rating());
+}
+
+#[test]
+fn interpolation_table() {
+    let table = LinearInterpolationTable::new(LimitBehaviour::Clamp, vec![(0.0, 1.0), (2.0, 2.0)])
+        .with_name("test table");
+    let json = serde_json::to_string(&table).unwrap();
+    let restored: LinearInterpolationTable = serde_json::from_str(&json).unwrap();
+    assert_close(restored.get(1.0), 1.5);
+    assert_close(restored.get(5.0), 2.0);
+    assert_eq!(restored.name(), "test table");
+
+    let table = LinearInterpolationTable::new(
+        LimitBehaviour::Clamp,
+        vec![(0.0, 1.0), (2.0, 2.0), (3.0, 5.0)],
+    )
+    .with_interpolation_kind(InterpolationKind::MonotoneCubic);
+    let json = serde_json::to_string(&table).unwrap();
+    let restored: LinearInterpolationTable = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        restored.interpolation_kind(),
+        InterpolationKind::MonotoneCubic
+    );
+    assert_close(restored.get(1.0), table.get(1.0));
+
+    let unsorted = r#"{"table": [[0.0, 1.0], [2.0, 2.0], [1.0, 0.0]], "limit_behaviour": "Clamp"}"#;
+    let error = serde_json::from_str::<LinearInterpolationTable>(unsorted).unwrap_err();
+    assert!(error.to_string().contains("entry 2"), "{error}");
+}
+
+#[test]
+fn grid_interpolation_table() {
+    let table = GridInterpolationTable::new(
+        LimitBehaviour::Clamp,
+        vec![0.0, 1.0, 2.0],
+        vec![0.0, 1.0],
+        vec![vec![0.0, 1.0], vec![1.0, 3.0], vec![4.0, 5.0]],
+    )
+    .with_interpolation_kind(GridInterpolationKind::Bicubic);
+    let json = serde_json::to_string(&table).unwrap();
+    let restored: GridInterpolationTable = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        restored.interpolation_kind(),
+        GridInterpolationKind::Bicubic
+    );
+    assert_close(restored.get(1.5, 0.5), table.get(1.5, 0.5));
+
+    let ragged = r#"{"x_keys": [0.0, 1.0], "y_keys": [0.0], "values": [[0.0], []], "limit_behaviour": "Clamp"}"#;
+    assert!(serde_json::from_str::<GridInterpolationTable>(ragged).is_err());
+}
+
+#[test]
+fn format_version() {
+    let json = format!(
+        r#"{{"format_version": {}, "state": 1.0}}"#,
+        FORMAT_VERSION + 1
+    );
+    let snapshot: Snapshot<Pressure> = serde_json::from_str(&json).unwrap();
+    assert!(matches!(
+        snapshot.into_state(),
+        Err(Error::UnsupportedFormatVersion { version, supported })
+            if version == FORMAT_VERSION + 1 && supported == FORMAT_VERSION
+    ));
+}
@@ -0,0 +1,346 @@
+use crate::error::Error;
+use crate::substance::water::PropertyBackend;
+use crate::substance::{check_mass, Substance};
+use uom::num_traits::Zero;
+use uom::si::available_energy::joule_per_gram;
+use uom::si::f64::{AvailableEnergy, Energy, HeatTransfer, MolarHeatCapacity, MolarMass, Pressure};
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::molar_heat_capacity::joule_per_kelvin_mole;
+use uom::si::molar_mass::gram_per_mole;
+use uom::si::{
+    f64::{Mass, MassDensity, SpecificHeatCapacity, ThermodynamicTemperature, Volume},
+    mass::kilogram,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    temperature_interval,
+    thermodynamic_temperature::kelvin,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Heavy water (D2O), as used e.g. as moderator and coolant in CANDU reactors.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeavyWater {
+    #[cfg_attr(feature = "serde", serde(rename = "mass_kg"))]
+    mass: Mass,
+    #[cfg_attr(feature = "serde", serde(rename = "temperature_k"))]
+    temperature: ThermodynamicTemperature,
+}
+
+impl HeavyWater {
+    pub fn new(mass: Mass, temperature: ThermodynamicTemperature) -> Self {
+        Self { mass, temperature }
+    }
+}
+
+impl Substance for HeavyWater {
+    const LIQUID_NAME: &'static str = "Heavy water";
+    const VAPOUR_NAME: &'static str = "Heavy water vapour";
+
+    fn zero() -> Self {
+        Self {
+            mass: Mass::new::<kilogram>(0.0),
+            temperature: ThermodynamicTemperature::new::<kelvin>(0.0),
+        }
+    }
+
+    fn mass(&self) -> Mass {
+        self.mass
+    }
+
+    fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// The density of this heavy water at its temperature.
+    /// Pressure is assumed to be roughly one bar for temperatures below 101°C,
+    /// and above that it is assumed to be saturation pressure.
+    fn density(&self) -> MassDensity {
+        density_by_temperature(self.temperature)
+    }
+
+    /// Remove some mass from this heavy water while keeping temperature.
+    fn try_remove(&mut self, mass: Mass) -> Result<HeavyWater, Error> {
+        check_mass(mass, self.mass)?;
+        self.mass -= mass;
+        Ok(HeavyWater { mass, ..*self })
+    }
+
+    fn checked_add_energy(self, energy: Energy) -> Result<Self, Error> {
+        if energy.is_zero() {
+            return Ok(self);
+        }
+        if self.mass.is_zero() {
+            return Err(Error::ZeroMassEnergyTransfer);
+        }
+        let temperature_difference = energy / heat_capacity() / self.mass;
+        Ok(Self {
+            temperature: self.temperature + temperature_difference,
+            ..self
+        })
+    }
+
+    fn pressure(&self, volume: Volume) -> Pressure {
+        // ideal gas law pV = mR'T, where R' is R scaled for the molar mass of heavy water.
+        self.mass * specific_gas_constant() * self.temperature / volume
+    }
+
+    fn saturation_pressure(&self) -> Pressure {
+        saturation_pressure_by_temperature(self.temperature)
+    }
+
+    fn heat_capacity(&self) -> SpecificHeatCapacity {
+        heat_capacity()
+    }
+
+    /// Approximated by the bulk modulus of light water at the same temperature.
+    /// The compressibility of heavy water is within a few percent of that of light water below 300°C,
+    /// but it vanishes at the slightly lower critical temperature of heavy water.
+    fn bulk_modulus(&self) -> Pressure {
+        PropertyBackend::Approximate.bulk_modulus(self.temperature)
+    }
+
+    fn latent_heat(&self) -> AvailableEnergy {
+        phase_change_energy()
+    }
+
+    fn critical_temperature(&self) -> ThermodynamicTemperature {
+        critical_temperature()
+    }
+
+    fn gas_constant(&self) -> SpecificHeatCapacity {
+        specific_gas_constant()
+    }
+
+    fn gas_liquid_heat_transfer_coefficient(&self) -> HeatTransfer {
+        gas_liquid_heat_transfer_coefficient()
+    }
+
+    /// Assume that this heavy water is liquid.
+    /// Compute the temperature after the given mass has evaporated away.
+    /// We assume that the evaporation energy will be taken from the remaining heavy water and the evaporating heavy water equally.
+    fn evaporate(&mut self, mass: Mass) -> Result<Self, Error> {
+        check_mass(mass, self.mass)?;
+        let evaporation_energy = phase_change_energy() * mass;
+        let cooled_self = self.checked_add_energy(-evaporation_energy)?;
+
+        if cooled_self.temperature().get::<kelvin>() <= 0.0 {
+            Err(Error::NonPositiveTemperature)
+        } else {
+            *self = cooled_self;
+            self.try_remove(mass)
+        }
+    }
+
+    /// Assume that this heavy water is gaseous.
+    /// Compute the temperature after the given mass has condensated away.
+    /// We assume that the condensation energy will be deposited into the remaining heavy water and the condensating heavy water equally.
+    fn try_condensate(&mut self, mass: Mass) -> Result<Self, Error> {
+        check_mass(mass, self.mass)?;
+        let condensation_energy = phase_change_energy() * mass;
+        let mut heated_self = self.checked_add_energy(condensation_energy)?;
+        let condensate = heated_self.try_remove(mass)?;
+        *self = heated_self;
+        Ok(condensate)
+    }
+}
+
+impl std::ops::Add for HeavyWater {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mass = self.mass + rhs.mass;
+        let temperature = if mass > Mass::zero() {
+            ThermodynamicTemperature::new::<kelvin>(
+                ((self.mass * self.temperature + rhs.mass * rhs.temperature) / mass)
+                    .get::<temperature_interval::kelvin>(),
+            )
+        } else {
+            ThermodynamicTemperature::new::<kelvin>(0.0)
+        };
+
+        Self { mass, temperature }
+    }
+}
+
+impl std::ops::AddAssign for HeavyWater {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Sub<Mass> for HeavyWater {
+    type Output = Self;
+
+    /// Panics if the mass is negative or more than there is, see [`Substance::try_remove`].
+    fn sub(self, rhs: Mass) -> Self::Output {
+        check_mass(rhs, self.mass).unwrap_or_else(|error| panic!("{error}"));
+        Self {
+            mass: self.mass - rhs,
+            ..self
+        }
+    }
+}
+
+impl std::ops::SubAssign<Mass> for HeavyWater {
+    fn sub_assign(&mut self, rhs: Mass) {
+        *self = *self - rhs;
+    }
+}
+
+impl std::ops::Add<Energy> for HeavyWater {
+    type Output = Self;
+
+    fn add(self, rhs: Energy) -> Self::Output {
+        self.checked_add_energy(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl std::ops::AddAssign<Energy> for HeavyWater {
+    fn add_assign(&mut self, rhs: Energy) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Sub<Energy> for HeavyWater {
+    type Output = Self;
+
+    fn sub(self, rhs: Energy) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl std::ops::SubAssign<Energy> for HeavyWater {
+    fn sub_assign(&mut self, rhs: Energy) {
+        *self = *self - rhs;
+    }
+}
+
+mod constants {
+    use lazy_static::lazy_static;
+
+    // Densities and vapour pressures approximated from the IAPWS formulation for heavy water (IAPWS R16-17),
+    // via the ratios to the properties of light water.
+    use crate::interpolation_table::quantity::QuantityTable;
+    use crate::interpolation_table::LimitBehaviour;
+    use uom::si::f64::{MassDensity, Pressure, ThermodynamicTemperature};
+    use uom::si::mass_density::gram_per_cubic_centimeter;
+    use uom::si::pressure::millibar;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+
+    lazy_static! {
+        /// Celsius -> g/cm^3
+        /// High temperatures (above 101.4) roughly at boiling pressure
+        pub static ref DENSITY_BY_TEMPERATURE: QuantityTable<ThermodynamicTemperature, MassDensity> =
+            QuantityTable::from_units(LimitBehaviour::Clamp, ThermodynamicTemperature::new::<degree_celsius>, MassDensity::new::<gram_per_cubic_centimeter>, vec![
+                (3.82, 1.1053),
+                (11.2, 1.1060),
+                (20.0, 1.1053),
+                (25.0, 1.1044),
+                (30.0, 1.1034),
+                (40.0, 1.0999),
+                (50.0, 1.0961),
+                (60.0, 1.0911),
+                (70.0, 1.0852),
+                (80.0, 1.0786),
+                (90.0, 1.0712),
+                (100.0, 1.0631),
+                (125.0, 1.0395),
+                (150.0, 1.0151),
+                (175.0, 0.9874),
+                (200.0, 0.9572),
+                (250.0, 0.8845),
+                (300.0, 0.7884),
+                (350.0, 0.640),
+                (360.0, 0.575),
+                (370.697, 0.356),
+                (400.0, 0.1358),
+                (450.0, 0.1005),
+                (500.0, 0.0850),
+                (600.0, 0.0683),
+                (700.0, 0.0584),
+                (800.0, 0.0516),
+                (900.0, 0.0464),
+                (1000.0, 0.0423),
+                (2000.0, 0.0232),
+            ])
+            .with_name("heavy water density by temperature");
+
+        /// mbar -> Celsius
+        /// ends at the critical point, above which there is no phase change
+        pub static ref BOILING_POINT_BY_PRESSURE_RAW: Vec<(f64, f64)> = vec![
+            (6.605, 3.82),
+            (10.15, 10.0),
+            (27.32, 25.0),
+            (111.3, 50.0),
+            (358.9, 75.0),
+            (962.0, 100.0),
+            (1013.25, 101.42),
+            (2254.0, 125.0),
+            (4669.0, 150.0),
+            (8838.0, 175.0),
+            (15470.0, 200.0),
+            (25510.0, 225.0),
+            (40160.0, 250.0),
+            (60320.0, 275.0),
+            (87630.0, 300.0),
+            (123690.0, 325.0),
+            (170400.0, 350.0),
+            (192830.0, 360.0),
+            (216618.0, 370.697),
+        ];
+
+        pub static ref BOILING_POINT_BY_PRESSURE: QuantityTable<Pressure, ThermodynamicTemperature> = QuantityTable::from_units(LimitBehaviour::Clamp, Pressure::new::<millibar>, ThermodynamicTemperature::new::<degree_celsius>, BOILING_POINT_BY_PRESSURE_RAW.iter().copied()).with_name("heavy water boiling point by pressure");
+
+        pub static ref SATURATION_PRESSURE_BY_TEMPERATURE: QuantityTable<ThermodynamicTemperature, Pressure> = QuantityTable::from_units(LimitBehaviour::Clamp, ThermodynamicTemperature::new::<degree_celsius>, Pressure::new::<millibar>, BOILING_POINT_BY_PRESSURE_RAW.iter().copied().map(|(pressure, temperature)| (temperature, pressure))).with_name("heavy water saturation pressure by temperature");
+    }
+}
+
+fn density_by_temperature(temperature: ThermodynamicTemperature) -> MassDensity {
+    constants::DENSITY_BY_TEMPERATURE.get(temperature)
+}
+
+pub fn boiling_point_by_pressure(pressure: Pressure) -> ThermodynamicTemperature {
+    constants::BOILING_POINT_BY_PRESSURE.get(pressure)
+}
+
+fn saturation_pressure_by_temperature(temperature: ThermodynamicTemperature) -> Pressure {
+    constants::SATURATION_PRESSURE_BY_TEMPERATURE.get(temperature)
+}
+
+/// The molar mass of heavy water.
+pub fn molar_mass() -> MolarMass {
+    MolarMass::new::<gram_per_mole>(20.0276)
+}
+
+/// The specific gas constant R' = R / M of heavy water.
+pub fn specific_gas_constant() -> SpecificHeatCapacity {
+    MolarHeatCapacity::new::<joule_per_kelvin_mole>(8.314_462_618) / molar_mass()
+}
+
+/// The heat capacity of heavy water.
+/// This is an approximation with the value of the liquid at about 25°C, used for all temperatures and phases.
+/// The actual heat capacity of the liquid varies by a few percent up to 300°C, and that of the vapour is about half of it.
+pub fn heat_capacity() -> SpecificHeatCapacity {
+    SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4210.0)
+}
+
+/// The temperature above which heavy water does not evaporate or condensate.
+pub fn critical_temperature() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(643.847)
+}
+
+/// The energy required to evaporate heavy water, and set free by condensing heavy water.
+/// This is an approximation with the value at the normal boiling point, used for all temperatures.
+/// The actual latent heat decreases with temperature and vanishes at the critical point.
+pub fn phase_change_energy() -> AvailableEnergy {
+    AvailableEnergy::new::<joule_per_gram>(2070.0)
+}
+
+/// The heat transfer coefficient between heavy water steam and heavy water without a separating surface.
+/// This is assumed to be the same as for light water.
+pub fn gas_liquid_heat_transfer_coefficient() -> HeatTransfer {
+    HeatTransfer::new::<watt_per_square_meter_kelvin>(2800.0)
+}
@@ -0,0 +1,68 @@
+use uom::si::{
+    f64::{Mass, Pressure, ThermodynamicTemperature},
+    mass::kilogram,
+    mass_density::kilogram_per_cubic_meter,
+    pressure::atmosphere,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+use super::{boiling_point_by_pressure, HeavyWater};
+use crate::substance::Substance;
+
+#[test]
+fn add() {
+    let heavy_water1 = HeavyWater::new(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<kelvin>(400.0),
+    );
+
+    let heavy_water2 = HeavyWater::new(
+        Mass::new::<kilogram>(2.0),
+        ThermodynamicTemperature::new::<kelvin>(100.0),
+    );
+
+    let heavy_water_sum = heavy_water1 + heavy_water2;
+    assert!((heavy_water_sum.mass().get::<kilogram>() - 3.0).abs() < 1e-10);
+    assert!((heavy_water_sum.temperature().get::<kelvin>() - 200.0).abs() < 1e-10);
+}
+
+#[test]
+fn evaporate_condensate() {
+    let mut heavy_water = HeavyWater::new(
+        Mass::new::<kilogram>(10.0),
+        ThermodynamicTemperature::new::<degree_celsius>(200.0),
+    );
+
+    let mut steam = heavy_water.evaporate(Mass::new::<kilogram>(1.0)).unwrap();
+    assert!((heavy_water.mass().get::<kilogram>() - 9.0).abs() < 1e-10);
+    assert!((steam.mass().get::<kilogram>() - 1.0).abs() < 1e-10);
+    assert!(heavy_water.temperature().get::<degree_celsius>() < 200.0);
+
+    // Condensing everything again restores the original state.
+    let condensed = steam.condensate(Mass::new::<kilogram>(1.0));
+    let heavy_water = heavy_water + condensed;
+    assert!((heavy_water.mass().get::<kilogram>() - 10.0).abs() < 1e-10);
+    assert!((heavy_water.temperature().get::<degree_celsius>() - 200.0).abs() < 1e-10);
+}
+
+#[test]
+fn normal_boiling_point() {
+    let boiling_point = boiling_point_by_pressure(Pressure::new::<atmosphere>(1.0));
+    assert!((boiling_point.get::<degree_celsius>() - 101.42).abs() < 1e-2);
+
+    let heavy_water = HeavyWater::new(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(25.0),
+    );
+    assert!((heavy_water.density().get::<kilogram_per_cubic_meter>() - 1104.4).abs() < 1e-1);
+}
+
+#[test]
+#[should_panic(expected = "cannot take 2 kg from 1 kg")]
+fn remove_too_much() {
+    let heavy_water = HeavyWater::new(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+    );
+    let _ = heavy_water - Mass::new::<kilogram>(2.0);
+}
@@ -0,0 +1,502 @@
+use crate::error::{Error, Result};
+use crate::substance::{check_mass, Substance};
+use uom::num_traits::Zero;
+use uom::si::f64::{
+    AvailableEnergy, Energy, HeatTransfer, Mass, MassDensity, MolarHeatCapacity, MolarMass,
+    Pressure, SpecificHeatCapacity, ThermalConductivity, ThermodynamicTemperature, Volume,
+};
+use uom::si::{
+    available_energy::{joule_per_kilogram, kilojoule_per_kilogram},
+    heat_transfer::watt_per_square_meter_kelvin,
+    mass_density::kilogram_per_cubic_meter,
+    molar_heat_capacity::joule_per_kelvin_mole,
+    molar_mass::gram_per_mole,
+    pressure::gigapascal,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The liquid metals that can be used as coolant.
+///
+/// The correlations are taken from the OECD/NEA handbook on lead-bismuth eutectic alloy and lead properties (2015),
+/// and from Fink and Leibowitz, "Thermodynamic and transport properties of sodium liquid and vapor" (1995).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LiquidMetalCoolant {
+    Sodium,
+    LeadBismuthEutectic,
+}
+
+/// A liquid metal coolant.
+/// Unlike water, it is always single-phase: operations that would freeze or boil it fail.
+/// In a [`crate::container::Container`], its vapour pressure is neglected, so the gas stays empty.
+///
+/// The internal energy is tracked using the temperature-dependent heat capacity of the coolant,
+/// so heating, cooling and mixing conserve energy.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LiquidMetal {
+    coolant: LiquidMetalCoolant,
+    #[cfg_attr(feature = "serde", serde(rename = "mass_kg"))]
+    mass: Mass,
+    #[cfg_attr(feature = "serde", serde(rename = "temperature_k"))]
+    temperature: ThermodynamicTemperature,
+}
+
+impl LiquidMetalCoolant {
+    pub fn melting_point(self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<kelvin>(match self {
+            LiquidMetalCoolant::Sodium => 370.98,
+            LiquidMetalCoolant::LeadBismuthEutectic => 398.0,
+        })
+    }
+
+    pub fn boiling_point(self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<kelvin>(match self {
+            LiquidMetalCoolant::Sodium => 1156.5,
+            LiquidMetalCoolant::LeadBismuthEutectic => 1927.0,
+        })
+    }
+
+    /// The critical temperature of the coolant.
+    /// For lead-bismuth eutectic, this is an estimate from the critical temperatures of lead and bismuth.
+    pub fn critical_temperature(self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<kelvin>(match self {
+            LiquidMetalCoolant::Sodium => 2503.7,
+            LiquidMetalCoolant::LeadBismuthEutectic => 4890.0,
+        })
+    }
+
+    /// The energy required to evaporate the coolant at its normal boiling point.
+    pub fn latent_heat(self) -> AvailableEnergy {
+        AvailableEnergy::new::<kilojoule_per_kilogram>(match self {
+            LiquidMetalCoolant::Sodium => 3871.0,
+            LiquidMetalCoolant::LeadBismuthEutectic => 852.0,
+        })
+    }
+
+    pub fn molar_mass(self) -> MolarMass {
+        MolarMass::new::<gram_per_mole>(match self {
+            LiquidMetalCoolant::Sodium => 22.98977,
+            LiquidMetalCoolant::LeadBismuthEutectic => 208.2,
+        })
+    }
+
+    /// The isothermal bulk modulus of the liquid coolant.
+    /// This is an approximation with the value at typical operating temperatures, used for all temperatures.
+    pub fn bulk_modulus(self) -> Pressure {
+        Pressure::new::<gigapascal>(match self {
+            LiquidMetalCoolant::Sodium => 5.2,
+            LiquidMetalCoolant::LeadBismuthEutectic => 35.0,
+        })
+    }
+
+    /// The density of the liquid coolant at the given temperature.
+    pub fn density(self, temperature: ThermodynamicTemperature) -> Result<MassDensity> {
+        let temperature = self.check_temperature(temperature)?;
+        let density = match self {
+            LiquidMetalCoolant::Sodium => {
+                let reduced = 1.0 - temperature / 2503.7;
+                219.0 + 275.32 * reduced + 511.58 * reduced.sqrt()
+            }
+            LiquidMetalCoolant::LeadBismuthEutectic => 11065.0 - 1.293 * temperature,
+        };
+        Ok(MassDensity::new::<kilogram_per_cubic_meter>(density))
+    }
+
+    /// The isobaric heat capacity of the liquid coolant at the given temperature.
+    pub fn heat_capacity(
+        self,
+        temperature: ThermodynamicTemperature,
+    ) -> Result<SpecificHeatCapacity> {
+        let temperature = self.check_temperature(temperature)?;
+        Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+            self.heat_capacity_at(temperature),
+        ))
+    }
+
+    /// The thermal conductivity of the liquid coolant at the given temperature.
+    pub fn thermal_conductivity(
+        self,
+        temperature: ThermodynamicTemperature,
+    ) -> Result<ThermalConductivity> {
+        let temperature = self.check_temperature(temperature)?;
+        let thermal_conductivity = match self {
+            LiquidMetalCoolant::Sodium => {
+                124.67 - 0.11381 * temperature + 5.5226e-5 * temperature.powi(2)
+                    - 1.1842e-8 * temperature.powi(3)
+            }
+            LiquidMetalCoolant::LeadBismuthEutectic => {
+                3.284 + 1.617e-2 * temperature - 2.305e-6 * temperature.powi(2)
+            }
+        };
+        Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
+            thermal_conductivity,
+        ))
+    }
+
+    /// Coefficients of cp = c0 + c1 T + c2 T^2 + c_2 T^-2 in J/kgK, with T in Kelvin.
+    fn heat_capacity_coefficients(self) -> [f64; 4] {
+        match self {
+            LiquidMetalCoolant::Sodium => [1658.2, -0.8479, 4.4541e-4, -2.9926e6],
+            LiquidMetalCoolant::LeadBismuthEutectic => [164.8, -3.94e-2, 1.25e-5, -4.56e5],
+        }
+    }
+
+    /// The specific enthalpy in J/kg relative to the liquid at the melting point.
+    /// The temperature is in Kelvin and not checked.
+    fn specific_enthalpy(self, temperature: f64) -> f64 {
+        let [c0, c1, c2, c_2] = self.heat_capacity_coefficients();
+        let antiderivative = |temperature: f64| {
+            c0 * temperature + c1 / 2.0 * temperature.powi(2) + c2 / 3.0 * temperature.powi(3)
+                - c_2 / temperature
+        };
+        antiderivative(temperature) - antiderivative(self.melting_point().get::<kelvin>())
+    }
+
+    /// The temperature at which the liquid coolant has the given specific enthalpy in J/kg.
+    fn temperature_by_specific_enthalpy(self, specific_enthalpy: f64) -> Result<f64> {
+        let mut lower = self.melting_point().get::<kelvin>();
+        let mut upper = self.boiling_point().get::<kelvin>();
+        if specific_enthalpy < 0.0 {
+            return Err(Error::LiquidMetalFrozen {
+                coolant: format!("{self:?}"),
+                temperature: lower + specific_enthalpy / self.heat_capacity_at(lower),
+            });
+        }
+        if specific_enthalpy > self.specific_enthalpy(upper) {
+            return Err(Error::LiquidMetalBoiling {
+                coolant: format!("{self:?}"),
+                temperature: upper
+                    + (specific_enthalpy - self.specific_enthalpy(upper))
+                        / self.heat_capacity_at(upper),
+            });
+        }
+
+        // Safeguarded Newton iteration. The enthalpy is strictly increasing with temperature.
+        let mut temperature = lower + specific_enthalpy / self.heat_capacity_at(lower);
+        for _ in 0..100 {
+            temperature = temperature.clamp(lower, upper);
+            let residual = self.specific_enthalpy(temperature) - specific_enthalpy;
+            if residual > 0.0 {
+                upper = temperature;
+            } else {
+                lower = temperature;
+            }
+
+            let step = residual / self.heat_capacity_at(temperature);
+            if step.abs() < 1e-10 * temperature {
+                return Ok(temperature - step);
+            }
+            temperature -= step;
+            if temperature <= lower || temperature >= upper {
+                temperature = (lower + upper) / 2.0;
+            }
+        }
+
+        Ok(temperature)
+    }
+
+    /// The heat capacity in J/kgK at the given temperature in Kelvin, not checked.
+    fn heat_capacity_at(self, temperature: f64) -> f64 {
+        let [c0, c1, c2, c_2] = self.heat_capacity_coefficients();
+        c0 + c1 * temperature + c2 * temperature.powi(2) + c_2 * temperature.powi(-2)
+    }
+
+    /// Return the temperature in Kelvin if the coolant is liquid at it.
+    fn check_temperature(self, temperature: ThermodynamicTemperature) -> Result<f64> {
+        let kelvin_temperature = temperature.get::<kelvin>();
+        if temperature < self.melting_point() {
+            Err(Error::LiquidMetalFrozen {
+                coolant: format!("{self:?}"),
+                temperature: kelvin_temperature,
+            })
+        } else if temperature > self.boiling_point() {
+            Err(Error::LiquidMetalBoiling {
+                coolant: format!("{self:?}"),
+                temperature: kelvin_temperature,
+            })
+        } else {
+            Ok(kelvin_temperature)
+        }
+    }
+}
+
+impl LiquidMetal {
+    /// Create a new liquid metal coolant.
+    /// Fails if the temperature is not between the melting point and the boiling point.
+    pub fn new(
+        coolant: LiquidMetalCoolant,
+        mass: Mass,
+        temperature: ThermodynamicTemperature,
+    ) -> Result<Self> {
+        coolant.check_temperature(temperature)?;
+        Ok(Self {
+            coolant,
+            mass,
+            temperature,
+        })
+    }
+
+    pub fn coolant(&self) -> LiquidMetalCoolant {
+        self.coolant
+    }
+
+    pub fn thermal_conductivity(&self) -> ThermalConductivity {
+        self.coolant
+            .thermal_conductivity(self.temperature)
+            .expect("the temperature is checked on every update")
+    }
+
+    /// The internal energy of this coolant relative to the liquid at the melting point.
+    pub fn energy(&self) -> Energy {
+        self.mass
+            * AvailableEnergy::new::<joule_per_kilogram>(
+                self.coolant
+                    .specific_enthalpy(self.temperature.get::<kelvin>()),
+            )
+    }
+
+    /// Fail with an error if some of this coolant should change its phase.
+    fn check_phase_change(&self, mass: Mass) -> Result<()> {
+        check_mass(mass, self.mass)?;
+        if mass.is_zero() {
+            Ok(())
+        } else {
+            Err(Error::LiquidMetalPhaseChange {
+                coolant: format!("{:?}", self.coolant),
+            })
+        }
+    }
+}
+
+impl Substance for LiquidMetal {
+    const LIQUID_NAME: &'static str = "Liquid metal";
+    const VAPOUR_NAME: &'static str = "Metal vapour";
+
+    /// An empty amount of sodium at its melting point.
+    /// As it is empty, it can be mixed with any coolant.
+    fn zero() -> Self {
+        Self {
+            coolant: LiquidMetalCoolant::Sodium,
+            mass: Mass::zero(),
+            temperature: LiquidMetalCoolant::Sodium.melting_point(),
+        }
+    }
+
+    fn mass(&self) -> Mass {
+        self.mass
+    }
+
+    fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    fn density(&self) -> MassDensity {
+        self.coolant
+            .density(self.temperature)
+            .expect("the temperature is checked on every update")
+    }
+
+    /// The pressure of the coolant vapour, by the ideal gas law.
+    fn pressure(&self, volume: Volume) -> Pressure {
+        self.mass * self.gas_constant() * self.temperature / volume
+    }
+
+    /// The vapour pressure of liquid metals is negligible below their boiling points, so it is treated as zero.
+    fn saturation_pressure(&self) -> Pressure {
+        Pressure::zero()
+    }
+
+    fn heat_capacity(&self) -> SpecificHeatCapacity {
+        self.coolant
+            .heat_capacity(self.temperature)
+            .expect("the temperature is checked on every update")
+    }
+
+    fn bulk_modulus(&self) -> Pressure {
+        self.coolant.bulk_modulus()
+    }
+
+    fn latent_heat(&self) -> AvailableEnergy {
+        self.coolant.latent_heat()
+    }
+
+    fn critical_temperature(&self) -> ThermodynamicTemperature {
+        self.coolant.critical_temperature()
+    }
+
+    fn gas_constant(&self) -> SpecificHeatCapacity {
+        MolarHeatCapacity::new::<joule_per_kelvin_mole>(8.314_462_618) / self.coolant.molar_mass()
+    }
+
+    /// The coolant vapour is neglected, so this only matters for an unusual container that was created with some.
+    fn gas_liquid_heat_transfer_coefficient(&self) -> HeatTransfer {
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(100.0)
+    }
+
+    /// Remove some mass from this coolant while keeping temperature.
+    fn try_remove(&mut self, mass: Mass) -> Result<LiquidMetal> {
+        check_mass(mass, self.mass)?;
+        self.mass -= mass;
+        Ok(LiquidMetal { mass, ..*self })
+    }
+
+    /// Mix another amount of the same coolant into this one, conserving energy.
+    /// Returns an error if the coolants are different, unless one of them is empty.
+    fn checked_add(self, rhs: Self) -> Result<Self> {
+        if rhs.mass.is_zero() {
+            return Ok(self);
+        }
+        if self.mass.is_zero() {
+            return Ok(rhs);
+        }
+        if self.coolant != rhs.coolant {
+            return Err(Error::MixedLiquidMetalCoolants {
+                coolant: format!("{:?}", self.coolant),
+                other: format!("{:?}", rhs.coolant),
+            });
+        }
+
+        let mass = self.mass + rhs.mass;
+        let specific_enthalpy = ((self.energy() + rhs.energy()) / mass).get::<joule_per_kilogram>();
+        let temperature = self
+            .coolant
+            .temperature_by_specific_enthalpy(specific_enthalpy)?;
+
+        Ok(Self {
+            mass,
+            temperature: ThermodynamicTemperature::new::<kelvin>(temperature),
+            ..self
+        })
+    }
+
+    /// Heat this coolant by the given energy, or cool it if the energy is negative.
+    /// Fails if the coolant would boil or freeze, or if there is no mass to take the energy.
+    fn checked_add_energy(self, energy: Energy) -> Result<Self> {
+        if energy.is_zero() {
+            return Ok(self);
+        }
+        if self.mass.is_zero() {
+            return Err(Error::ZeroMassEnergyTransfer);
+        }
+        let specific_enthalpy = ((self.energy() + energy) / self.mass).get::<joule_per_kilogram>();
+        let temperature = self
+            .coolant
+            .temperature_by_specific_enthalpy(specific_enthalpy)?;
+
+        Ok(Self {
+            temperature: ThermodynamicTemperature::new::<kelvin>(temperature),
+            ..self
+        })
+    }
+
+    /// The coolant does not evaporate, so this fails unless the mass is zero.
+    fn evaporate(&mut self, mass: Mass) -> Result<Self> {
+        self.check_phase_change(mass)?;
+        Ok(Self { mass, ..*self })
+    }
+
+    /// The coolant does not condensate, so this fails unless the mass is zero.
+    fn try_condensate(&mut self, mass: Mass) -> Result<Self> {
+        self.check_phase_change(mass)?;
+        Ok(Self { mass, ..*self })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LiquidMetal {
+    /// Deserialise a coolant, checking it like [`LiquidMetal::new`].
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Unchecked {
+            coolant: LiquidMetalCoolant,
+            #[serde(rename = "mass_kg")]
+            mass: Mass,
+            #[serde(rename = "temperature_k")]
+            temperature: ThermodynamicTemperature,
+        }
+
+        let Unchecked {
+            coolant,
+            mass,
+            temperature,
+        } = Unchecked::deserialize(deserializer)?;
+        Self::new(coolant, mass, temperature).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::ops::Add for LiquidMetal {
+    type Output = Self;
+
+    /// Mix two amounts of the same coolant, conserving energy.
+    /// Panics if they cannot be mixed, see [`Substance::checked_add`].
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl std::ops::AddAssign for LiquidMetal {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Sub<Mass> for LiquidMetal {
+    type Output = Self;
+
+    /// Panics if the mass is negative or more than there is, see [`Substance::try_remove`].
+    fn sub(self, rhs: Mass) -> Self::Output {
+        check_mass(rhs, self.mass).unwrap_or_else(|error| panic!("{error}"));
+        Self {
+            mass: self.mass - rhs,
+            ..self
+        }
+    }
+}
+
+impl std::ops::SubAssign<Mass> for LiquidMetal {
+    fn sub_assign(&mut self, rhs: Mass) {
+        *self = *self - rhs;
+    }
+}
+
+impl std::ops::Add<Energy> for LiquidMetal {
+    type Output = Self;
+
+    /// Heat this coolant.
+    /// Panics if the coolant would boil, or if there is no mass to take the energy, see [`Substance::checked_add_energy`].
+    fn add(self, rhs: Energy) -> Self::Output {
+        self.checked_add_energy(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl std::ops::AddAssign<Energy> for LiquidMetal {
+    fn add_assign(&mut self, rhs: Energy) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Sub<Energy> for LiquidMetal {
+    type Output = Self;
+
+    /// Cool this coolant.
+    /// Panics if the coolant would freeze, see [`Substance::checked_add_energy`].
+    fn sub(self, rhs: Energy) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl std::ops::SubAssign<Energy> for LiquidMetal {
+    fn sub_assign(&mut self, rhs: Energy) {
+        *self = *self - rhs;
+    }
+}
@@ -0,0 +1,130 @@
+use uom::num_traits::Zero;
+use uom::si::{
+    area::square_meter,
+    energy::megajoule,
+    f64::{Area, Energy, Mass, ThermodynamicTemperature, Volume},
+    mass::kilogram,
+    mass_density::kilogram_per_cubic_meter,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    volume::cubic_meter,
+};
+
+use super::{LiquidMetal, LiquidMetalCoolant};
+use crate::container::Container;
+use crate::error::Error;
+use crate::substance::Substance;
+
+fn sodium(mass: f64, temperature: f64) -> LiquidMetal {
+    LiquidMetal::new(
+        LiquidMetalCoolant::Sodium,
+        Mass::new::<kilogram>(mass),
+        ThermodynamicTemperature::new::<degree_celsius>(temperature),
+    )
+    .unwrap()
+}
+
+#[test]
+fn density() {
+    let sodium = sodium(1.0, 400.0);
+    assert!((sodium.density().get::<kilogram_per_cubic_meter>() - 857.7).abs() < 0.5);
+
+    let lead_bismuth = LiquidMetal::new(
+        LiquidMetalCoolant::LeadBismuthEutectic,
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<kelvin>(700.0),
+    )
+    .unwrap();
+    assert!((lead_bismuth.density().get::<kilogram_per_cubic_meter>() - 10159.9).abs() < 0.5);
+}
+
+#[test]
+fn heat_and_mix() {
+    let cold = sodium(2.0, 300.0);
+    let hot = sodium(1.0, 600.0);
+    let energy = cold.energy() + hot.energy();
+
+    let mixed = cold + hot;
+    assert!((mixed.energy() - energy).abs() < Energy::new::<megajoule>(1e-9));
+    let temperature = mixed.temperature().get::<degree_celsius>();
+    assert!(temperature > 390.0 && temperature < 410.0);
+
+    let lead_bismuth = LiquidMetal::new(
+        LiquidMetalCoolant::LeadBismuthEutectic,
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<kelvin>(700.0),
+    )
+    .unwrap();
+    assert!(matches!(
+        mixed.checked_add(lead_bismuth),
+        Err(Error::MixedLiquidMetalCoolants { coolant, other })
+            if coolant == "Sodium" && other == "LeadBismuthEutectic"
+    ));
+
+    let heated = mixed + Energy::new::<megajoule>(1.0);
+    let cooled = heated - Energy::new::<megajoule>(1.0);
+    assert!(
+        (cooled.temperature().get::<kelvin>() - mixed.temperature().get::<kelvin>()).abs() < 1e-6
+    );
+}
+
+#[test]
+fn phase_limits() {
+    assert!(matches!(
+        LiquidMetal::new(
+            LiquidMetalCoolant::Sodium,
+            Mass::new::<kilogram>(1.0),
+            ThermodynamicTemperature::new::<degree_celsius>(50.0),
+        ),
+        Err(Error::LiquidMetalFrozen { coolant, .. }) if coolant == "Sodium"
+    ));
+
+    let sodium = sodium(1.0, 200.0);
+    assert!(matches!(
+        sodium.checked_add_energy(Energy::new::<megajoule>(-1.0)),
+        Err(Error::LiquidMetalFrozen { .. })
+    ));
+    assert!(matches!(
+        sodium.checked_add_energy(Energy::new::<megajoule>(10.0)),
+        Err(Error::LiquidMetalBoiling { .. })
+    ));
+}
+
+#[test]
+fn mass_and_energy_errors() {
+    let mut sodium = sodium(1.0, 400.0);
+    assert!(matches!(
+        sodium.try_remove(Mass::new::<kilogram>(2.0)),
+        Err(Error::InsufficientMass { .. })
+    ));
+    assert!(matches!(
+        sodium.try_remove(Mass::new::<kilogram>(-1.0)),
+        Err(Error::NegativeMass { .. })
+    ));
+
+    let removed = sodium.try_remove(Mass::new::<kilogram>(1.0)).unwrap();
+    assert_eq!(removed.temperature(), sodium.temperature());
+    assert!(matches!(
+        sodium.checked_add_energy(Energy::new::<megajoule>(1.0)),
+        Err(Error::ZeroMassEnergyTransfer)
+    ));
+}
+
+#[test]
+fn container() {
+    let sodium = sodium(1000.0, 400.0);
+    let mut container = Container::new(
+        Volume::new::<cubic_meter>(2.0),
+        Area::new::<square_meter>(1.0),
+        sodium,
+        LiquidMetal::zero(),
+    );
+    container.evaporate_condensate().unwrap();
+    assert_eq!(container.liquid().mass(), sodium.mass());
+    assert!(container.vapour().mass().is_zero());
+
+    let mut liquid = *container.liquid();
+    assert!(matches!(
+        liquid.evaporate(Mass::new::<kilogram>(1.0)),
+        Err(Error::LiquidMetalPhaseChange { coolant }) if coolant == "Sodium"
+    ));
+}
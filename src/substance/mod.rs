@@ -1 +1,196 @@
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use uom::num_traits::Zero;
+use uom::si::f64::{
+    AvailableEnergy, Energy, HeatTransfer, Mass, MassDensity, Pressure, Ratio,
+    SpecificHeatCapacity, ThermodynamicTemperature, Volume,
+};
+use uom::si::mass::kilogram;
+use uom::si::ratio::ratio;
+
+use crate::error::Error;
+
+pub mod heavy_water;
+pub mod liquid_metal;
+pub mod non_condensable_gas;
 pub mod water;
+
+/// A working fluid that can exist as liquid and as gas.
+///
+/// Adding two substances mixes them, updating the temperature.
+/// Adding or subtracting energy heats or cools the substance.
+pub trait Substance:
+    Debug
+    + Clone
+    + Copy
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Mass, Output = Self>
+    + SubAssign<Mass>
+    + Add<Energy, Output = Self>
+    + AddAssign<Energy>
+    + Sub<Energy, Output = Self>
+    + SubAssign<Energy>
+{
+    /// The name of the liquid of this substance, e.g. for display.
+    const LIQUID_NAME: &'static str;
+
+    /// The name of the gas of this substance, e.g. for display.
+    const VAPOUR_NAME: &'static str;
+
+    /// An empty amount of this substance.
+    fn zero() -> Self;
+
+    fn mass(&self) -> Mass;
+
+    fn temperature(&self) -> ThermodynamicTemperature;
+
+    /// The density of this substance at its temperature, assuming it is liquid.
+    fn density(&self) -> MassDensity;
+
+    /// The volume occupied by this substance at its temperature.
+    /// The substance is assumed to be liquid.
+    fn volume(&self) -> Volume {
+        self.mass() / self.density()
+    }
+
+    /// The pressure excerted by this substance at its temperature in the given volume.
+    /// The substance is assumed to be gaseous.
+    fn pressure(&self, volume: Volume) -> Pressure;
+
+    /// The saturation pressure of this substance based on its temperature.
+    fn saturation_pressure(&self) -> Pressure;
+
+    /// The heat capacity of this substance at its temperature.
+    fn heat_capacity(&self) -> SpecificHeatCapacity;
+
+    /// The isothermal bulk modulus of this substance at its temperature, assuming it is liquid.
+    /// This is the pressure needed to compress the liquid by a relative amount.
+    fn bulk_modulus(&self) -> Pressure;
+
+    /// The pressure of this substance at its temperature when it is liquid and confined to the given volume.
+    /// If the liquid fits into the volume, this is the saturation pressure.
+    /// Otherwise, the liquid is compressed, and the pressure rises according to the bulk modulus.
+    fn liquid_pressure(&self, volume: Volume) -> Pressure {
+        let compression = (self.volume() / volume).get::<ratio>();
+        if compression <= 1.0 {
+            self.saturation_pressure()
+        } else {
+            self.saturation_pressure() + self.bulk_modulus() * compression.ln()
+        }
+    }
+
+    /// The energy needed to evaporate a unit of mass of this substance.
+    fn latent_heat(&self) -> AvailableEnergy;
+
+    /// The temperature above which this substance is a single fluid phase, that does not evaporate or condensate.
+    fn critical_temperature(&self) -> ThermodynamicTemperature;
+
+    /// The specific gas constant of this substance.
+    fn gas_constant(&self) -> SpecificHeatCapacity;
+
+    /// The compressibility factor Z = pV / (mR'T) of the gas of this substance at its temperature and the given pressure.
+    /// It is one for an ideal gas.
+    fn compressibility_factor(&self, _pressure: Pressure) -> Ratio {
+        Ratio::new::<ratio>(1.0)
+    }
+
+    /// The heat transfer coefficient between the gaseous and the liquid phase of this substance.
+    fn gas_liquid_heat_transfer_coefficient(&self) -> HeatTransfer;
+
+    /// Remove some mass from this substance while keeping temperature.
+    /// Returns an error if the mass is negative or more than there is.
+    fn try_remove(&mut self, mass: Mass) -> Result<Self, Error>;
+
+    /// Remove some mass from this substance while keeping temperature.
+    /// Panics if the mass is negative or more than there is, see [`Substance::try_remove`].
+    fn remove(&mut self, mass: Mass) -> Self {
+        self.try_remove(mass)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Mix some other amount of this substance into this one, like [`Add`].
+    /// Returns an error if they cannot be mixed, e.g. because they are in different phases.
+    fn checked_add(self, rhs: Self) -> Result<Self, Error> {
+        Ok(self + rhs)
+    }
+
+    /// Heat this substance by the given energy, or cool it if the energy is negative.
+    /// Returns an error if there is no mass to take the energy.
+    fn checked_add_energy(self, energy: Energy) -> Result<Self, Error>;
+
+    /// Turn this substance into gas at the same temperature, as if it was just evaporated.
+    /// This is used for the gas of a [`crate::container::Container`], so that it may be created like the liquid.
+    /// Substances that do not track their phase are returned unchanged.
+    fn into_vapour(self) -> Self {
+        self
+    }
+
+    /// Assume that this substance is liquid.
+    /// Evaporate the given mass, returning the resulting gas.
+    fn evaporate(&mut self, mass: Mass) -> Result<Self, Error>;
+
+    /// Assume that this substance is gaseous.
+    /// Condensate the given mass, returning the resulting liquid.
+    /// Returns an error if the mass is negative or more than there is.
+    fn try_condensate(&mut self, mass: Mass) -> Result<Self, Error>;
+
+    /// Assume that this substance is gaseous.
+    /// Condensate the given mass, returning the resulting liquid.
+    /// Panics if the mass is negative or more than there is, see [`Substance::try_condensate`].
+    fn condensate(&mut self, mass: Mass) -> Self {
+        self.try_condensate(mass)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Move mass between this substance and another substance.
+    /// Specifically, first remove the mass from each substance while keeping temperature,
+    /// and then mix it with the other substance, while updating temperature.
+    /// Returns an error if a mass is negative or more than there is, or if the substances cannot be mixed.
+    /// Then both substances are left unchanged.
+    fn try_simultaneous_mass_exchange(
+        &mut self,
+        other: &mut Self,
+        outgoing_mass: Mass,
+        incoming_mass: Mass,
+    ) -> Result<(), Error> {
+        let mut remaining = *self;
+        let mut other_remaining = *other;
+        let outgoing = remaining.try_remove(outgoing_mass)?;
+        let incoming = other_remaining.try_remove(incoming_mass)?;
+        let mixed = remaining.checked_add(incoming)?;
+        let other_mixed = other_remaining.checked_add(outgoing)?;
+        *self = mixed;
+        *other = other_mixed;
+        Ok(())
+    }
+
+    /// Move mass between this substance and another substance.
+    /// Panics if this fails, see [`Substance::try_simultaneous_mass_exchange`].
+    fn simultaneous_mass_exchange(
+        &mut self,
+        other: &mut Self,
+        outgoing_mass: Mass,
+        incoming_mass: Mass,
+    ) {
+        self.try_simultaneous_mass_exchange(other, outgoing_mass, incoming_mass)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Check that the given mass can be taken from the available mass.
+pub(crate) fn check_mass(mass: Mass, available: Mass) -> Result<(), Error> {
+    if mass < Mass::zero() {
+        Err(Error::NegativeMass {
+            mass: mass.get::<kilogram>(),
+        })
+    } else if mass > available {
+        Err(Error::InsufficientMass {
+            requested: mass.get::<kilogram>(),
+            available: available.get::<kilogram>(),
+        })
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,244 @@
+use uom::num_traits::Zero;
+use uom::si::f64::{
+    Energy, HeatCapacity, Mass, MolarHeatCapacity, MolarMass, Pressure, SpecificHeatCapacity,
+    ThermodynamicTemperature, Volume,
+};
+use uom::si::{
+    energy::joule, heat_capacity::joule_per_kelvin, molar_heat_capacity::joule_per_kelvin_mole,
+    molar_mass::gram_per_mole, specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::error::Error;
+use crate::substance::check_mass;
+
+#[cfg(test)]
+mod tests;
+
+/// A gas that does not condense at the conditions inside of a reactor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonCondensableGas {
+    Nitrogen,
+    Oxygen,
+    Hydrogen,
+    /// Dry air, treated as a single ideal gas.
+    Air,
+}
+
+/// A mix of non-condensable gases, all at the same temperature.
+/// The gases are treated as ideal gases.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GasMixture {
+    #[cfg_attr(feature = "serde", serde(rename = "masses_kg"))]
+    masses: [Mass; 4],
+    #[cfg_attr(feature = "serde", serde(rename = "temperature_k"))]
+    temperature: ThermodynamicTemperature,
+}
+
+impl NonCondensableGas {
+    pub const ALL: [NonCondensableGas; 4] = [
+        NonCondensableGas::Nitrogen,
+        NonCondensableGas::Oxygen,
+        NonCondensableGas::Hydrogen,
+        NonCondensableGas::Air,
+    ];
+
+    pub fn molar_mass(self) -> MolarMass {
+        MolarMass::new::<gram_per_mole>(match self {
+            NonCondensableGas::Nitrogen => 28.0134,
+            NonCondensableGas::Oxygen => 31.9988,
+            NonCondensableGas::Hydrogen => 2.01588,
+            NonCondensableGas::Air => 28.9647,
+        })
+    }
+
+    /// The specific gas constant R' = R / M of this gas.
+    pub fn specific_gas_constant(self) -> SpecificHeatCapacity {
+        MolarHeatCapacity::new::<joule_per_kelvin_mole>(8.314_462_618) / self.molar_mass()
+    }
+
+    /// The isochoric heat capacity of this gas.
+    /// We treat it as the same over all temperatures.
+    pub fn heat_capacity(self) -> SpecificHeatCapacity {
+        SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(match self {
+            NonCondensableGas::Nitrogen => 743.0,
+            NonCondensableGas::Oxygen => 659.0,
+            NonCondensableGas::Hydrogen => 10183.0,
+            NonCondensableGas::Air => 718.0,
+        })
+    }
+
+    fn index(self) -> usize {
+        match self {
+            NonCondensableGas::Nitrogen => 0,
+            NonCondensableGas::Oxygen => 1,
+            NonCondensableGas::Hydrogen => 2,
+            NonCondensableGas::Air => 3,
+        }
+    }
+}
+
+impl GasMixture {
+    /// A mixture without any gas in it.
+    pub fn zero() -> Self {
+        Self {
+            masses: [Mass::zero(); 4],
+            temperature: ThermodynamicTemperature::new::<kelvin>(0.0),
+        }
+    }
+
+    /// A mixture of a single gas.
+    pub fn new(gas: NonCondensableGas, mass: Mass, temperature: ThermodynamicTemperature) -> Self {
+        let mut masses = [Mass::zero(); 4];
+        masses[gas.index()] = mass;
+        Self {
+            masses,
+            temperature,
+        }
+    }
+
+    /// The mass of the given gas in this mixture.
+    pub fn mass(&self, gas: NonCondensableGas) -> Mass {
+        self.masses[gas.index()]
+    }
+
+    pub fn total_mass(&self) -> Mass {
+        self.masses.iter().copied().sum()
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// The amount of energy needed to heat this mixture by one Kelvin.
+    pub fn heat_capacity(&self) -> HeatCapacity {
+        NonCondensableGas::ALL
+            .iter()
+            .map(|gas| self.mass(*gas) * gas.heat_capacity())
+            .fold(
+                HeatCapacity::new::<joule_per_kelvin>(0.0),
+                |sum, heat_capacity| sum + heat_capacity,
+            )
+    }
+
+    /// The pressure excerted by the given gas of this mixture at its temperature in the given volume.
+    pub fn partial_pressure(&self, gas: NonCondensableGas, volume: Volume) -> Pressure {
+        self.mass(gas) * gas.specific_gas_constant() * self.temperature / volume
+    }
+
+    /// The pressure excerted by this mixture at its temperature in the given volume.
+    /// By Dalton's law, this is the sum of the partial pressures of all gases.
+    pub fn pressure(&self, volume: Volume) -> Pressure {
+        NonCondensableGas::ALL
+            .iter()
+            .map(|gas| self.partial_pressure(*gas, volume))
+            .sum()
+    }
+
+    /// Remove some mass from this mixture while keeping temperature, taking each gas by its share of the mass.
+    /// Returns an error if the mass is negative or more than there is.
+    pub fn try_remove(&mut self, mass: Mass) -> Result<Self, Error> {
+        let total_mass = self.total_mass();
+        check_mass(mass, total_mass)?;
+        let mut removed = Self {
+            masses: [Mass::zero(); 4],
+            ..*self
+        };
+        if mass.is_zero() {
+            return Ok(removed);
+        }
+
+        let fraction = mass / total_mass;
+        for (remaining, removed) in self.masses.iter_mut().zip(removed.masses.iter_mut()) {
+            *removed = *remaining * fraction;
+            *remaining -= *removed;
+        }
+        Ok(removed)
+    }
+
+    /// Heat this mixture by the given energy, or cool it if the energy is negative.
+    /// Returns an error if there is no gas to take the energy.
+    pub fn checked_add_energy(self, energy: Energy) -> Result<Self, Error> {
+        if energy.is_zero() {
+            return Ok(self);
+        }
+        let heat_capacity = self.heat_capacity().get::<joule_per_kelvin>();
+        if heat_capacity <= 0.0 {
+            return Err(Error::ZeroMassEnergyTransfer);
+        }
+
+        let temperature_difference = energy.get::<joule>() / heat_capacity;
+        Ok(Self {
+            temperature: ThermodynamicTemperature::new::<kelvin>(
+                self.temperature.get::<kelvin>() + temperature_difference,
+            ),
+            ..self
+        })
+    }
+}
+
+impl std::ops::Add for GasMixture {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut masses = self.masses;
+        for (mass, rhs_mass) in masses.iter_mut().zip(rhs.masses) {
+            *mass += rhs_mass;
+        }
+
+        let self_heat_capacity = self.heat_capacity().get::<joule_per_kelvin>();
+        let rhs_heat_capacity = rhs.heat_capacity().get::<joule_per_kelvin>();
+        let heat_capacity = self_heat_capacity + rhs_heat_capacity;
+        let temperature = if heat_capacity > 0.0 {
+            ThermodynamicTemperature::new::<kelvin>(
+                (self_heat_capacity * self.temperature.get::<kelvin>()
+                    + rhs_heat_capacity * rhs.temperature.get::<kelvin>())
+                    / heat_capacity,
+            )
+        } else {
+            ThermodynamicTemperature::new::<kelvin>(0.0)
+        };
+
+        Self {
+            masses,
+            temperature,
+        }
+    }
+}
+
+impl std::ops::AddAssign for GasMixture {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Add<Energy> for GasMixture {
+    type Output = Self;
+
+    /// Panics if there is no gas to take the energy, see [`GasMixture::checked_add_energy`].
+    fn add(self, rhs: Energy) -> Self::Output {
+        self.checked_add_energy(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl std::ops::AddAssign<Energy> for GasMixture {
+    fn add_assign(&mut self, rhs: Energy) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Sub<Energy> for GasMixture {
+    type Output = Self;
+
+    fn sub(self, rhs: Energy) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl std::ops::SubAssign<Energy> for GasMixture {
+    fn sub_assign(&mut self, rhs: Energy) {
+        *self = *self - rhs;
+    }
+}
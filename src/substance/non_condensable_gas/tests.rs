@@ -0,0 +1,112 @@
+use uom::si::{
+    energy::kilojoule,
+    f64::{Energy, Mass, ThermodynamicTemperature, Volume},
+    mass::kilogram,
+    pressure::pascal,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    volume::cubic_meter,
+};
+
+use super::{GasMixture, NonCondensableGas};
+use crate::error::Error;
+use uom::num_traits::Zero;
+
+#[test]
+fn air_at_normal_conditions() {
+    // Dry air has a density of about 1.2041 kg/m^3 at 20°C and 101325 Pa.
+    let air = GasMixture::new(
+        NonCondensableGas::Air,
+        Mass::new::<kilogram>(1.2041),
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+    );
+    let pressure = air.pressure(Volume::new::<cubic_meter>(1.0));
+    assert!((pressure.get::<pascal>() - 101325.0).abs() < 50.0);
+}
+
+#[test]
+fn partial_pressures() {
+    let temperature = ThermodynamicTemperature::new::<kelvin>(300.0);
+    let volume = Volume::new::<cubic_meter>(2.0);
+    let mixture = GasMixture::new(
+        NonCondensableGas::Nitrogen,
+        Mass::new::<kilogram>(3.0),
+        temperature,
+    ) + GasMixture::new(
+        NonCondensableGas::Hydrogen,
+        Mass::new::<kilogram>(0.2),
+        temperature,
+    );
+
+    assert!((mixture.temperature().get::<kelvin>() - 300.0).abs() < 1e-10);
+    assert!((mixture.total_mass().get::<kilogram>() - 3.2).abs() < 1e-10);
+
+    let sum: f64 = NonCondensableGas::ALL
+        .iter()
+        .map(|gas| mixture.partial_pressure(*gas, volume).get::<pascal>())
+        .sum();
+    assert!((mixture.pressure(volume).get::<pascal>() - sum).abs() < 1e-6);
+
+    // Hydrogen has a much lower molar mass, so a small mass has a large partial pressure.
+    assert!(
+        mixture.partial_pressure(NonCondensableGas::Hydrogen, volume)
+            > mixture.partial_pressure(NonCondensableGas::Nitrogen, volume) / 2.0
+    );
+}
+
+#[test]
+fn remove() {
+    let temperature = ThermodynamicTemperature::new::<kelvin>(300.0);
+    let mut mixture = GasMixture::new(
+        NonCondensableGas::Nitrogen,
+        Mass::new::<kilogram>(3.0),
+        temperature,
+    ) + GasMixture::new(
+        NonCondensableGas::Hydrogen,
+        Mass::new::<kilogram>(1.0),
+        temperature,
+    );
+
+    // Venting takes each gas by its share of the mass.
+    let vented = mixture.try_remove(Mass::new::<kilogram>(2.0)).unwrap();
+    assert!((vented.mass(NonCondensableGas::Nitrogen).get::<kilogram>() - 1.5).abs() < 1e-12);
+    assert!((vented.mass(NonCondensableGas::Hydrogen).get::<kilogram>() - 0.5).abs() < 1e-12);
+    assert!((mixture.total_mass().get::<kilogram>() - 2.0).abs() < 1e-12);
+    assert_eq!(vented.temperature(), mixture.temperature());
+
+    assert!(matches!(
+        mixture.try_remove(Mass::new::<kilogram>(3.0)),
+        Err(Error::InsufficientMass { .. })
+    ));
+    assert!(matches!(
+        mixture.try_remove(Mass::new::<kilogram>(-1.0)),
+        Err(Error::NegativeMass { .. })
+    ));
+    assert!(GasMixture::zero()
+        .try_remove(Mass::zero())
+        .unwrap()
+        .total_mass()
+        .is_zero());
+}
+
+#[test]
+fn heat() {
+    let nitrogen = GasMixture::new(
+        NonCondensableGas::Nitrogen,
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+    );
+    let heated = nitrogen
+        .checked_add_energy(Energy::new::<kilojoule>(1.0))
+        .unwrap();
+    let expected = 1000.0 / NonCondensableGas::Nitrogen.heat_capacity().value;
+    assert!(
+        (heated.temperature().get::<kelvin>() - nitrogen.temperature().get::<kelvin>() - expected)
+            .abs()
+            < 1e-10
+    );
+
+    assert!(matches!(
+        GasMixture::zero().checked_add_energy(Energy::new::<kilojoule>(1.0)),
+        Err(Error::ZeroMassEnergyTransfer)
+    ));
+}
@@ -1,143 +1,687 @@
 use crate::error::Error;
+use crate::interpolation_table::quantity::QuantityTable;
+use crate::substance::water::chemistry::Chemistry;
+use crate::substance::water::equation_of_state::SteamEquationOfState;
+use crate::substance::water::expansion::Expansion;
+use crate::substance::water::iapws_if97::Region;
+use crate::substance::water::isotopes::{Isotope, IsotopeInventory};
+use crate::substance::{check_mass, Substance};
 use std::marker::PhantomData;
-use typenum::{N1, N2, P2, Z0};
 use uom::num_traits::Zero;
-use uom::si::available_energy::joule_per_gram;
-use uom::si::f64::{AvailableEnergy, Energy, HeatTransfer, Pressure, TemperatureInterval};
+use uom::si::available_energy::kilojoule_per_kilogram;
+use uom::si::f64::{
+    AvailableEnergy, ElectricalConductivity, Energy, HeatCapacity, HeatTransfer, Pressure,
+    Radioactivity, Ratio, TemperatureInterval, Time,
+};
 use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::pressure::{atmosphere, bar, pascal};
 use uom::si::ratio::ratio;
 use uom::si::{
     f64::{Mass, MassDensity, SpecificHeatCapacity, ThermodynamicTemperature, Volume},
     mass::kilogram,
     mass_density::gram_per_cubic_centimeter,
-    pressure::millibar,
-    specific_heat_capacity::joule_per_kilogram_kelvin,
     temperature_interval,
     thermodynamic_temperature::degree_celsius,
     thermodynamic_temperature::kelvin,
-    Quantity, ISQ, SI,
+    volume::cubic_meter,
+    Quantity,
 };
 
+pub mod chemistry;
+pub mod equation_of_state;
+pub mod expansion;
+pub mod flash;
+pub mod iapws_if97;
+pub mod isotopes;
+pub mod transport;
+pub mod two_phase;
+
 #[cfg(test)]
 mod tests;
 
+/// Some water, either liquid or steam.
+/// Liquid water below its melting point freezes, so it may contain ice.
+///
+/// The state is stored as specific enthalpy, and the temperature is derived from it.
+/// Hence, heating, mixing and phase change conserve energy exactly, even though the heat capacity depends on the temperature.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Water {
+    #[cfg_attr(feature = "serde", serde(rename = "mass_kg"))]
     mass: Mass,
-    temperature: ThermodynamicTemperature,
+    #[cfg_attr(feature = "serde", serde(rename = "specific_enthalpy_j_per_kg"))]
+    specific_enthalpy: AvailableEnergy,
+    /// For steam, the difference between its specific enthalpy and the specific enthalpy of ideal-gas steam at its temperature.
+    /// This is set when the steam is created such that the latent heat is conserved.
+    /// Always zero for liquid water.
+    #[cfg_attr(feature = "serde", serde(rename = "enthalpy_offset_j_per_kg"))]
+    enthalpy_offset: AvailableEnergy,
+    /// The concentration of boron dissolved as boric acid, by mass.
+    /// Boron does not evaporate, so this is zero for steam, unless it was left over when all water evaporated.
+    #[cfg_attr(feature = "serde", serde(rename = "boron_concentration_kg_per_kg"))]
+    boron_concentration: Ratio,
+    chemistry: Chemistry,
+    isotopes: IsotopeInventory,
+    phase: Phase,
+    property_backend: PropertyBackend,
+    equation_of_state: SteamEquationOfState,
+}
+
+/// The phase of some [`Water`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Phase {
+    Liquid,
+    Vapour,
+    /// Liquid water heated above the critical temperature.
+    /// It is a single fluid phase that does not evaporate or condensate.
+    Supercritical,
+    /// Liquid water that is frozen completely.
+    /// Partially frozen water is still liquid, see [`Water::ice_mass`].
+    Ice,
+}
+
+/// The method used to compute the thermodynamic properties of water.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PropertyBackend {
+    /// Linear interpolation of tabulated values along the saturation line, and the ideal gas law for steam.
+    /// This is fast, but off by a lot at high pressures.
+    #[default]
+    Approximate,
+    /// The IAPWS-IF97 formulation, see [`iapws_if97`].
+    /// Temperatures and pressures outside of its range of validity are clamped.
+    /// Where a clamped state is still rejected, e.g. by rounding at the boundaries, the approximate values are used instead.
+    IapwsIf97,
 }
 
 impl Water {
+    /// Create liquid water, or ice below the melting point.
+    /// To create steam, use [`Water::new_steam`].
+    /// Water passed as the gas of a [`crate::container::Container`] is turned into steam, see [`Substance::into_vapour`].
     pub fn new(mass: Mass, temperature: ThermodynamicTemperature) -> Self {
-        Self { mass, temperature }
+        Self {
+            mass,
+            specific_enthalpy: liquid_specific_enthalpy(temperature),
+            enthalpy_offset: AvailableEnergy::zero(),
+            boron_concentration: Ratio::zero(),
+            chemistry: Chemistry::zero(),
+            isotopes: IsotopeInventory::zero(),
+            phase: Phase::Liquid,
+            property_backend: PropertyBackend::default(),
+            equation_of_state: SteamEquationOfState::default(),
+        }
+    }
+
+    /// Create steam, as if it was just evaporated from liquid water at the same temperature.
+    pub fn new_steam(mass: Mass, temperature: ThermodynamicTemperature) -> Self {
+        let specific_enthalpy =
+            liquid_specific_enthalpy(temperature) + phase_change_energy(temperature);
+        Self {
+            mass,
+            specific_enthalpy,
+            enthalpy_offset: specific_enthalpy - vapour_specific_enthalpy(temperature),
+            boron_concentration: Ratio::zero(),
+            chemistry: Chemistry::zero(),
+            isotopes: IsotopeInventory::zero(),
+            phase: Phase::Vapour,
+            property_backend: PropertyBackend::default(),
+            equation_of_state: SteamEquationOfState::default(),
+        }
     }
 
-    pub fn zero() -> Self {
+    /// Use the given backend for computing the properties of this water.
+    pub fn with_property_backend(self, property_backend: PropertyBackend) -> Self {
         Self {
-            mass: Mass::new::<kilogram>(0.0),
-            temperature: ThermodynamicTemperature::new::<kelvin>(0.0),
+            property_backend,
+            ..self
+        }
+    }
+
+    pub fn property_backend(&self) -> PropertyBackend {
+        self.property_backend
+    }
+
+    /// Use the given equation of state for the pressure of steam.
+    /// It only applies to [`PropertyBackend::Approximate`], as IAPWS-IF97 already describes steam as a real gas.
+    pub fn with_equation_of_state(self, equation_of_state: SteamEquationOfState) -> Self {
+        Self {
+            equation_of_state,
+            ..self
+        }
+    }
+
+    pub fn equation_of_state(&self) -> SteamEquationOfState {
+        self.equation_of_state
+    }
+
+    /// Dissolve boron in this water, such that it has the given concentration by mass.
+    /// Steam cannot hold boron, so for steam the concentration is ignored and stays zero.
+    pub fn with_boron_concentration(self, boron_concentration: Ratio) -> Self {
+        if self.phase == Phase::Vapour {
+            return self;
+        }
+        Self {
+            boron_concentration,
+            ..self
+        }
+    }
+
+    /// The concentration of dissolved boron by mass, usually given in ppm.
+    pub fn boron_concentration(&self) -> Ratio {
+        self.boron_concentration
+    }
+
+    /// The mass of dissolved boron.
+    pub fn boron_mass(&self) -> Mass {
+        self.mass * self.boron_concentration
+    }
+
+    /// Set the concentrations of the other chemicals dissolved in this water.
+    pub fn with_chemistry(self, chemistry: Chemistry) -> Self {
+        Self { chemistry, ..self }
+    }
+
+    pub fn chemistry(&self) -> &Chemistry {
+        &self.chemistry
+    }
+
+    /// The pH of this water at 25°C.
+    pub fn ph(&self) -> f64 {
+        self.chemistry.ph(self.boron_concentration)
+    }
+
+    /// The electrical conductivity of this water at 25°C.
+    pub fn conductivity(&self) -> ElectricalConductivity {
+        self.chemistry.conductivity(self.boron_concentration)
+    }
+
+    /// Set the radioactive isotopes carried by this water.
+    pub fn with_isotopes(self, isotopes: IsotopeInventory) -> Self {
+        Self { isotopes, ..self }
+    }
+
+    pub fn isotopes(&self) -> &IsotopeInventory {
+        &self.isotopes
+    }
+
+    /// The activity of the given isotope in this water.
+    pub fn activity(&self, isotope: Isotope) -> Radioactivity {
+        self.isotopes.activity(isotope, self.mass)
+    }
+
+    /// Let the isotopes in this water decay for the given time.
+    pub fn decay(&mut self, time: Time) {
+        self.isotopes.decay(time);
+    }
+
+    /// The phase of this water.
+    /// Liquid water above the critical temperature is supercritical.
+    pub fn phase(&self) -> Phase {
+        if self.phase == Phase::Liquid && self.temperature() >= critical_temperature() {
+            Phase::Supercritical
+        } else if self.phase == Phase::Liquid && self.ice_fraction() >= 1.0 {
+            Phase::Ice
+        } else {
+            self.phase
+        }
+    }
+
+    /// The mass of the ice in this water.
+    /// At the melting point, water and ice coexist, and the ice fraction follows from the enthalpy.
+    pub fn ice_mass(&self) -> Mass {
+        self.mass * self.ice_fraction()
+    }
+
+    fn ice_fraction(&self) -> f64 {
+        if self.phase == Phase::Vapour {
+            return 0.0;
+        }
+
+        let melting_specific_enthalpy = liquid_specific_enthalpy(melting_point());
+        ((melting_specific_enthalpy - self.specific_enthalpy) / fusion_energy())
+            .get::<ratio>()
+            .clamp(0.0, 1.0)
+    }
+
+    pub fn specific_enthalpy(&self) -> AvailableEnergy {
+        self.specific_enthalpy
+    }
+
+    /// The density of this liquid water at its temperature and the given pressure.
+    /// Unlike [`Substance::density`], this takes the compression of the liquid into account.
+    pub fn density_at_pressure(&self, pressure: Pressure) -> MassDensity {
+        let liquid_density = self
+            .property_backend
+            .compressed_liquid_density(self.temperature(), pressure);
+        let ice_fraction = self.ice_fraction();
+        if ice_fraction > 0.0 {
+            1.0 / (ice_fraction / ice_density() + (1.0 - ice_fraction) / liquid_density)
+        } else {
+            liquid_density
         }
     }
 
-    pub fn mass(&self) -> Mass {
+    /// Remove some mass while keeping temperature.
+    /// Returns an error if the mass is negative or more than there is.
+    pub fn checked_sub_mass(self, mass: Mass) -> Result<Self, Error> {
+        check_mass(mass, self.mass)?;
+        Ok(Self {
+            mass: self.mass - mass,
+            ..self
+        })
+    }
+
+    /// Remove the mass that takes up the given volume while keeping temperature.
+    /// Returns an error if the volume is negative or more than this water takes up.
+    pub fn checked_sub_volume(self, volume: Volume) -> Result<Self, Error> {
+        let available = self.volume();
+        if volume < Volume::zero() || volume > available {
+            return Err(Error::VolumeExceedsAvailable {
+                requested: volume.get::<cubic_meter>(),
+                available: available.get::<cubic_meter>(),
+            });
+        }
+        let fraction = (volume / available).get::<ratio>();
+        self.checked_sub_mass(self.mass * fraction)
+    }
+
+    /// Cool this water by the given energy, or heat it if the energy is negative.
+    /// Returns an error if there is no mass to take the energy.
+    pub fn checked_sub_energy(self, energy: Energy) -> Result<Self, Error> {
+        self.checked_add_energy(-energy)
+    }
+
+    fn check_phase(&self, expected: Phase) -> Result<(), Error> {
+        if self.phase == expected {
+            Ok(())
+        } else {
+            Err(Error::UnexpectedPhase {
+                expected: format!("{expected:?}"),
+                actual: format!("{:?}", self.phase),
+            })
+        }
+    }
+
+    pub fn enthalpy(&self) -> Energy {
+        self.mass * self.specific_enthalpy
+    }
+
+    /// The specific entropy of this water at the given pressure.
+    /// It is computed by IAPWS-IF97 from the specific enthalpy, as both use the same reference state.
+    pub fn specific_entropy(&self, pressure: Pressure) -> Result<SpecificHeatCapacity, Error> {
+        flash::by_pressure_enthalpy(pressure, self.specific_enthalpy)
+            .map(|state| state.specific_entropy())
+    }
+
+    /// The entropy of this water at the given pressure, see [`Water::specific_entropy`].
+    pub fn entropy(&self, pressure: Pressure) -> Result<HeatCapacity, Error> {
+        Ok(self.specific_entropy(pressure)? * self.mass)
+    }
+
+    /// Expand this water from the inlet pressure to the outlet pressure, as in a turbine stage or a nozzle.
+    /// The enthalpy drop is taken from this water, see [`expansion::expand`].
+    ///
+    /// The specific enthalpy has the same reference state as IAPWS-IF97, so the inlet and outlet states are computed from it directly.
+    /// Afterwards, this water takes the temperature of the outlet state, and becomes steam if the outlet is superheated steam,
+    /// leaving the boron behind, or liquid if it is compressed liquid.
+    /// Moisture formed by expanding steam stays in the steam until it condenses in a container,
+    /// and steam formed by expanding liquid stays in the liquid until it evaporates.
+    pub fn expand(
+        &mut self,
+        inlet_pressure: Pressure,
+        outlet_pressure: Pressure,
+        isentropic_efficiency: Option<Ratio>,
+    ) -> Result<Expansion, Error> {
+        let inlet = flash::by_pressure_enthalpy(inlet_pressure, self.specific_enthalpy)?;
+        let expansion = expansion::expand(&inlet, outlet_pressure, isentropic_efficiency)?;
+        let outlet = expansion.outlet();
+
+        self.specific_enthalpy -= expansion.enthalpy_drop();
+        self.phase = match outlet.region() {
+            Region::Region1 => Phase::Liquid,
+            Region::Region2 | Region::Region5 => Phase::Vapour,
+            Region::Region3 | Region::Region4 => self.phase,
+        };
+        if self.phase == Phase::Vapour {
+            self.boron_concentration = Ratio::zero();
+            self.enthalpy_offset =
+                self.specific_enthalpy - vapour_specific_enthalpy(outlet.temperature());
+        } else {
+            self.enthalpy_offset = AvailableEnergy::zero();
+        }
+        Ok(expansion)
+    }
+
+    /// Compute the amount of mass that can evaporate, leaving the water at the given temperature.
+    pub fn maximum_evaporable_amount(&self, target_temperature: ThermodynamicTemperature) -> Mass {
+        if target_temperature >= self.temperature() {
+            return Mass::zero();
+        }
+
+        let available_evaporation_energy =
+            self.mass * (self.specific_enthalpy - liquid_specific_enthalpy(target_temperature));
+        available_evaporation_energy / phase_change_energy(target_temperature)
+    }
+}
+
+impl Substance for Water {
+    const LIQUID_NAME: &'static str = "Water";
+    const VAPOUR_NAME: &'static str = "Steam";
+
+    fn zero() -> Self {
+        Self::new(
+            Mass::new::<kilogram>(0.0),
+            ThermodynamicTemperature::new::<kelvin>(0.0),
+        )
+    }
+
+    fn mass(&self) -> Mass {
         self.mass
     }
 
-    pub fn temperature(&self) -> ThermodynamicTemperature {
-        self.temperature
+    fn temperature(&self) -> ThermodynamicTemperature {
+        match self.phase {
+            Phase::Liquid | Phase::Supercritical | Phase::Ice => {
+                liquid_temperature(self.specific_enthalpy)
+            }
+            Phase::Vapour => vapour_temperature(self.specific_enthalpy - self.enthalpy_offset),
+        }
     }
 
-    /// The volume occupied by this water at its temperature.
-    /// The water is assumed to be liquid.
+    /// The density of this water at its temperature.
     /// Pressure is assumed to be roughly one bar for temperatures below 100°C,
     /// and above that it is assumed to be saturation pressure.
-    pub fn volume(&self) -> Volume {
-        let density = density_by_temperature(self.temperature);
-        self.mass / density
+    fn density(&self) -> MassDensity {
+        let liquid_density = self.property_backend.liquid_density(self.temperature());
+        let ice_fraction = self.ice_fraction();
+        if ice_fraction > 0.0 {
+            1.0 / (ice_fraction / ice_density() + (1.0 - ice_fraction) / liquid_density)
+        } else {
+            liquid_density
+        }
     }
 
     /// Remove some mass from this water while keeping temperature.
-    pub fn remove(&mut self, mass: Mass) -> Water {
-        assert!(mass <= self.mass);
+    fn try_remove(&mut self, mass: Mass) -> Result<Water, Error> {
+        check_mass(mass, self.mass)?;
         self.mass -= mass;
-        Water {
+        Ok(Water { mass, ..*self })
+    }
+
+    /// Mix some other water into this water, conserving energy and the dissolved substances.
+    /// Returns an error if one is liquid and the other is steam.
+    fn checked_add(self, rhs: Self) -> Result<Self, Error> {
+        if rhs.mass.is_zero() {
+            return Ok(self);
+        }
+        if self.mass.is_zero() {
+            return Ok(Self {
+                property_backend: self.property_backend,
+                equation_of_state: self.equation_of_state,
+                ..rhs
+            });
+        }
+        rhs.check_phase(self.phase)?;
+
+        let mass = self.mass + rhs.mass;
+        let self_fraction = (self.mass / mass).get::<ratio>();
+        let rhs_fraction = (rhs.mass / mass).get::<ratio>();
+
+        Ok(Self {
             mass,
-            temperature: self.temperature,
+            specific_enthalpy: self.specific_enthalpy * self_fraction
+                + rhs.specific_enthalpy * rhs_fraction,
+            enthalpy_offset: self.enthalpy_offset * self_fraction
+                + rhs.enthalpy_offset * rhs_fraction,
+            boron_concentration: self.boron_concentration * self_fraction
+                + rhs.boron_concentration * rhs_fraction,
+            chemistry: self
+                .chemistry
+                .mix(self_fraction, rhs.chemistry, rhs_fraction),
+            isotopes: self.isotopes.mix(self_fraction, rhs.isotopes, rhs_fraction),
+            ..self
+        })
+    }
+
+    fn checked_add_energy(self, energy: Energy) -> Result<Self, Error> {
+        if energy.is_zero() {
+            return Ok(self);
         }
+        if self.mass.is_zero() {
+            return Err(Error::ZeroMassEnergyTransfer);
+        }
+        Ok(Self {
+            specific_enthalpy: self.specific_enthalpy + energy / self.mass,
+            ..self
+        })
     }
 
-    /// The pressure excerted by this water at its temperature in the given volume.
-    /// The water is assumed to be gaseous.
-    /// The pressure is computed using the ideal gas law.
-    pub fn pressure(&self, volume: Volume) -> Pressure {
-        // ideal gas law pV = mR'T, where R' is R scaled for the molar mass of water.
-        // R' = R / 0.018015kg/mol
-        let right_side = self.mass * SPECIAL_IDEAL_GAS_CONSTANT * self.temperature;
-        right_side / volume
+    fn pressure(&self, volume: Volume) -> Pressure {
+        self.property_backend.vapour_pressure(
+            self.equation_of_state,
+            self.mass,
+            self.temperature(),
+            volume,
+        )
     }
 
-    /// The saturation pressure of this water based on its temperature.
-    pub fn saturation_pressure(&self) -> Pressure {
-        saturation_pressure_by_temperature(self.temperature)
+    fn saturation_pressure(&self) -> Pressure {
+        self.property_backend
+            .saturation_pressure(self.temperature())
     }
 
-    /// Move mass between this water and another water.
-    /// Specifically, first remove the mass from each water while keeping temperature,
-    /// and then mix it with the other water, while updating temperature.
-    pub fn simultaneous_mass_exchange(
-        &mut self,
-        other: &mut Water,
-        outgoing_mass: Mass,
-        incoming_mass: Mass,
-    ) {
-        let outgoing_water = self.remove(outgoing_mass);
-        let incoming_water = other.remove(incoming_mass);
-        *self += incoming_water;
-        *other += outgoing_water;
+    /// The pressure at which this liquid water is compressed into the given volume.
+    /// This is the pressure at which [`Water::density_at_pressure`] is the mass divided by the volume, but at least the saturation pressure.
+    /// Above the pressures covered by the property backend, the pressure rises further according to the bulk modulus.
+    fn liquid_pressure(&self, volume: Volume) -> Pressure {
+        let compression = (self.volume() / volume).get::<ratio>();
+        let mut lower = self.saturation_pressure();
+        if compression <= 1.0 {
+            return lower;
+        }
+
+        // The pressure grid is coarser than the table of the uncompressed density,
+        // so it is scaled to match that at the saturation pressure, which keeps the pressure continuous.
+        let scale = (self.density() / self.density_at_pressure(lower)).get::<ratio>();
+        let density_at_pressure = |pressure| self.density_at_pressure(pressure) * scale;
+        let density = self.mass / volume;
+        let mut upper = self.property_backend.maximum_liquid_pressure();
+        let maximum_density = density_at_pressure(upper);
+        if density >= maximum_density {
+            return upper + self.bulk_modulus() * (density / maximum_density).get::<ratio>().ln();
+        }
+
+        // The density rises with the pressure, so search for the pressure with the given density.
+        while (upper - lower).get::<pascal>() > 1.0 {
+            let middle = (lower + upper) / 2.0;
+            if density_at_pressure(middle) < density {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+        (lower + upper) / 2.0
+    }
+
+    fn heat_capacity(&self) -> SpecificHeatCapacity {
+        match self.phase {
+            Phase::Liquid | Phase::Supercritical | Phase::Ice => {
+                liquid_heat_capacity(self.temperature())
+            }
+            Phase::Vapour => vapour_heat_capacity(self.temperature()),
+        }
+    }
+
+    fn bulk_modulus(&self) -> Pressure {
+        self.property_backend.bulk_modulus(self.temperature())
+    }
+
+    fn latent_heat(&self) -> AvailableEnergy {
+        phase_change_energy(self.temperature())
+    }
+
+    fn critical_temperature(&self) -> ThermodynamicTemperature {
+        critical_temperature()
+    }
+
+    fn gas_constant(&self) -> SpecificHeatCapacity {
+        SPECIAL_IDEAL_GAS_CONSTANT
+    }
+
+    fn compressibility_factor(&self, pressure: Pressure) -> Ratio {
+        self.property_backend.vapour_compressibility_factor(
+            self.equation_of_state,
+            pressure,
+            self.temperature(),
+        )
+    }
+
+    fn gas_liquid_heat_transfer_coefficient(&self) -> HeatTransfer {
+        gas_liquid_heat_transfer_coefficient()
+    }
+
+    /// Turn this water into steam at the same temperature, keeping the dissolved gases and isotopes, but not the boron.
+    /// Steam is returned unchanged.
+    fn into_vapour(self) -> Self {
+        if self.phase == Phase::Vapour {
+            return self;
+        }
+        Self::new_steam(self.mass, self.temperature())
+            .with_property_backend(self.property_backend)
+            .with_equation_of_state(self.equation_of_state)
+            .with_chemistry(self.chemistry)
+            .with_isotopes(self.isotopes)
     }
 
     /// Assume that this water is liquid.
     /// Compute the temperature after the given mass has evaporated away.
     /// We assume that the evaporation energy will be taken from the remaining water and the evaporating water equally.
-    pub fn evaporate(&mut self, mass: Mass) -> Result<Self, Error> {
-        assert!(mass >= Mass::zero() && mass <= self.mass);
-        let evaporation_energy = phase_change_energy() * mass;
-        let cooled_self = *self - evaporation_energy;
+    fn evaporate(&mut self, mass: Mass) -> Result<Self, Error> {
+        check_mass(mass, self.mass)?;
+        // Zero mass has no meaningful phase, so there is nothing to check.
+        if mass.is_zero() {
+            return Ok(Water {
+                mass,
+                boron_concentration: Ratio::zero(),
+                chemistry: Chemistry::zero(),
+                isotopes: IsotopeInventory::zero(),
+                phase: Phase::Vapour,
+                enthalpy_offset: self.specific_enthalpy
+                    - vapour_specific_enthalpy(self.temperature()),
+                ..*self
+            });
+        }
+        self.check_phase(Phase::Liquid)?;
 
-        if cooled_self.temperature().get::<kelvin>() <= 0.0 {
+        // The evaporation energy depends on the temperature after evaporation,
+        // so search for the specific enthalpy of the remaining water that conserves energy.
+        let evaporated_fraction = (mass / self.mass).get::<ratio>();
+        let target = self.specific_enthalpy.get::<kilojoule_per_kilogram>();
+        let evaporated_enthalpy = |specific_enthalpy: f64| {
+            let specific_enthalpy =
+                AvailableEnergy::new::<kilojoule_per_kilogram>(specific_enthalpy);
+            (specific_enthalpy
+                + phase_change_energy(liquid_temperature(specific_enthalpy)) * evaporated_fraction)
+                .get::<kilojoule_per_kilogram>()
+        };
+        let mut lower = target - constants::MAXIMUM_LATENT_HEAT * evaporated_fraction;
+        let mut upper = target;
+        while upper - lower > 1e-9 {
+            let middle = (lower + upper) / 2.0;
+            if evaporated_enthalpy(middle) < target {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+        let specific_enthalpy =
+            AvailableEnergy::new::<kilojoule_per_kilogram>((lower + upper) / 2.0);
+        let temperature = liquid_temperature(specific_enthalpy);
+
+        if temperature.get::<kelvin>() <= 0.0 {
             Err(Error::NonPositiveTemperature)
         } else {
-            *self = cooled_self;
-            *self -= mass;
-            Ok(Water { mass, ..*self })
-        }
-    }
+            // Put the remaining error of the search into the steam, such that energy is conserved exactly.
+            let enthalpy = self.enthalpy();
+            let remaining_mass = self.mass - mass;
+            let steam_specific_enthalpy = (enthalpy - remaining_mass * specific_enthalpy) / mass;
 
-    /// Compute the amount of mass that can evaporate, leaving the water at the given temperature.
-    pub fn maximum_evaporable_amount(&self, target_temperature: ThermodynamicTemperature) -> Mass {
-        if target_temperature >= self.temperature {
-            return Mass::zero();
-        }
+            // The boron stays in the remaining water, and the dissolved gases are mostly stripped into the steam.
+            // If no water remains, the steam carries all of it.
+            let boron_mass = self.boron_mass();
+            let steam_chemistry = self.chemistry.evaporate(mass / self.mass);
+            let steam_isotopes = self.isotopes.evaporate(mass / self.mass);
+            self.specific_enthalpy = specific_enthalpy;
+            self.mass = remaining_mass;
+            let steam_boron_concentration = if self.mass.is_zero() {
+                boron_mass / mass
+            } else {
+                self.boron_concentration = boron_mass / self.mass;
+                Ratio::zero()
+            };
 
-        // The following will work once this is implemented: https://github.com/iliekturtles/uom/issues/447
-        // let temperature_difference = self.temperature - target_temperature;
-        let temperature_difference =
-            TemperatureInterval::new::<uom::si::temperature_interval::kelvin>(
-                self.temperature.get::<kelvin>() - target_temperature.get::<kelvin>(),
-            );
-        let available_evaporation_energy = temperature_difference * self.mass * heat_capacity();
-        available_evaporation_energy / phase_change_energy()
+            Ok(Water {
+                mass,
+                specific_enthalpy: steam_specific_enthalpy,
+                enthalpy_offset: steam_specific_enthalpy - vapour_specific_enthalpy(temperature),
+                boron_concentration: steam_boron_concentration,
+                chemistry: steam_chemistry,
+                isotopes: steam_isotopes,
+                phase: Phase::Vapour,
+                ..*self
+            })
+        }
     }
 
     /// Assume that this water is gaseous.
     /// Compute the temperature after the given mass has condensated away.
     /// We assume that the condensation energy will be deposited into the remaining water and the condensating water equally.
-    pub fn condensate(&mut self, mass: Mass) -> Self {
-        assert!(mass >= Mass::zero() && mass <= self.mass);
-        let condensation_energy = phase_change_energy() * mass;
-        *self += condensation_energy;
-        *self -= mass;
-        Water { mass, ..*self }
+    /// The condensation energy is the difference between the enthalpy of the steam and of the liquid at the final temperature.
+    /// For steam that was evaporated at that temperature, this is exactly the latent heat there.
+    fn try_condensate(&mut self, mass: Mass) -> Result<Self, Error> {
+        check_mass(mass, self.mass)?;
+        // Zero mass has no meaningful phase, so there is nothing to check.
+        if mass.is_zero() {
+            return Ok(Water::new(mass, self.temperature())
+                .with_property_backend(self.property_backend)
+                .with_equation_of_state(self.equation_of_state)
+                .with_chemistry(self.chemistry)
+                .with_isotopes(self.isotopes));
+        }
+        self.check_phase(Phase::Vapour)?;
+        let remaining_mass = self.mass - mass;
+        let enthalpy = self.enthalpy();
+
+        // Find the common temperature of the condensate and the remaining steam that conserves energy.
+        let condensed_enthalpy = |temperature| {
+            mass * liquid_specific_enthalpy(temperature)
+                + remaining_mass * (vapour_specific_enthalpy(temperature) + self.enthalpy_offset)
+        };
+        let mut lower = 0.0;
+        let mut upper = constants::MAXIMUM_TEMPERATURE;
+        while upper - lower > 1e-9 {
+            let middle = (lower + upper) / 2.0;
+            if condensed_enthalpy(ThermodynamicTemperature::new::<kelvin>(middle)) < enthalpy {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+        let temperature = ThermodynamicTemperature::new::<kelvin>((lower + upper) / 2.0);
+
+        let condensate = Water::new(mass, temperature)
+            .with_property_backend(self.property_backend)
+            .with_equation_of_state(self.equation_of_state)
+            .with_chemistry(self.chemistry)
+            .with_boron_concentration(self.boron_concentration)
+            .with_isotopes(self.isotopes);
+        self.mass = remaining_mass;
+        if !remaining_mass.is_zero() {
+            // Put the remaining error of the search into the steam, such that energy is conserved exactly.
+            self.specific_enthalpy = (enthalpy - condensate.enthalpy()) / remaining_mass;
+        }
+        Ok(condensate)
     }
 }
 
@@ -145,17 +689,8 @@ impl std::ops::Add for Water {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let mass = self.mass + rhs.mass;
-        let temperature = if mass > Mass::zero() {
-            ThermodynamicTemperature::new::<kelvin>(
-                ((self.mass * self.temperature + rhs.mass * rhs.temperature) / mass)
-                    .get::<temperature_interval::kelvin>(),
-            )
-        } else {
-            ThermodynamicTemperature::new::<kelvin>(0.0)
-        };
-
-        Self { mass, temperature }
+        self.checked_add(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
@@ -169,11 +704,8 @@ impl std::ops::Sub<Mass> for Water {
     type Output = Self;
 
     fn sub(self, rhs: Mass) -> Self::Output {
-        assert!(rhs <= self.mass);
-        Self {
-            mass: self.mass - rhs,
-            ..self
-        }
+        self.checked_sub_mass(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
@@ -187,14 +719,8 @@ impl std::ops::Sub<Volume> for Water {
     type Output = Self;
 
     fn sub(self, rhs: Volume) -> Self::Output {
-        let volume = self.volume();
-        assert!(rhs <= volume);
-        let fraction = rhs / volume;
-        let fraction = fraction.get::<ratio>();
-        assert!((0.0..=1.0).contains(&fraction));
-        let mass = self.mass * fraction;
-
-        self - mass
+        self.checked_sub_volume(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
@@ -202,12 +728,8 @@ impl std::ops::Add<Energy> for Water {
     type Output = Self;
 
     fn add(self, rhs: Energy) -> Self::Output {
-        let temperature_difference = rhs / heat_capacity() / self.mass;
-
-        Self {
-            mass: self.mass,
-            temperature: self.temperature + temperature_difference,
-        }
+        self.checked_add_energy(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
@@ -231,18 +753,271 @@ impl std::ops::SubAssign<Energy> for Water {
     }
 }
 
+impl PropertyBackend {
+    /// The density of liquid water at the given temperature.
+    /// Pressure is assumed to be one atmosphere or the saturation pressure, whichever is higher.
+    /// Above the critical point, pressure is assumed to be the critical pressure.
+    pub fn liquid_density(self, temperature: ThermodynamicTemperature) -> MassDensity {
+        match self {
+            PropertyBackend::Approximate => density_by_temperature(temperature),
+            PropertyBackend::IapwsIf97 => {
+                let temperature = clamp_iapws_if97_temperature(temperature);
+                let pressure = if temperature < iapws_if97::critical_temperature() {
+                    self.saturation_pressure(temperature)
+                        .max(Pressure::new::<atmosphere>(1.0))
+                } else {
+                    iapws_if97::critical_pressure()
+                };
+                iapws_if97::properties(pressure, temperature)
+                    .map(|properties| properties.density())
+                    .unwrap_or_else(|_| density_by_temperature(temperature))
+            }
+        }
+    }
+
+    /// The density of liquid water at the given temperature and pressure.
+    /// Below the saturation pressure, this is the density of saturated liquid.
+    /// The approximate backend covers temperatures up to 350°C and pressures up to 250 bar, and clamps outside of that.
+    pub fn compressed_liquid_density(
+        self,
+        temperature: ThermodynamicTemperature,
+        pressure: Pressure,
+    ) -> MassDensity {
+        match self {
+            PropertyBackend::Approximate => density_by_temperature_pressure(temperature, pressure),
+            PropertyBackend::IapwsIf97 => {
+                let temperature = clamp_iapws_if97_temperature(temperature);
+                if temperature >= iapws_if97::critical_temperature() {
+                    return self.liquid_density(temperature);
+                }
+                let pressure = pressure.max(self.saturation_pressure(temperature));
+                match iapws_if97::properties(pressure, temperature) {
+                    Ok(properties) => properties.density(),
+                    // Above the range of validity, which is 1000 bar.
+                    Err(_) => self.liquid_density(temperature),
+                }
+            }
+        }
+    }
+
+    /// The highest pressure covered by [`PropertyBackend::compressed_liquid_density`].
+    fn maximum_liquid_pressure(self) -> Pressure {
+        match self {
+            PropertyBackend::Approximate => Pressure::new::<bar>(250.0),
+            PropertyBackend::IapwsIf97 => Pressure::new::<bar>(1000.0),
+        }
+    }
+
+    /// The isothermal bulk modulus of liquid water at the given temperature.
+    /// Pressure is assumed to be the same as for [`PropertyBackend::liquid_density`].
+    /// It vanishes at the critical point.
+    pub fn bulk_modulus(self, temperature: ThermodynamicTemperature) -> Pressure {
+        match self {
+            PropertyBackend::Approximate => bulk_modulus_by_temperature(temperature),
+            PropertyBackend::IapwsIf97 => {
+                let temperature = clamp_iapws_if97_temperature(temperature);
+                if temperature >= iapws_if97::critical_temperature() {
+                    return Pressure::zero();
+                }
+
+                // K = rho * dp/drho, approximated by compressing the liquid by one bar.
+                let pressure = self
+                    .saturation_pressure(temperature)
+                    .max(Pressure::new::<atmosphere>(1.0));
+                let step = Pressure::new::<bar>(1.0);
+                match (
+                    iapws_if97::properties(pressure, temperature),
+                    iapws_if97::properties(pressure + step, temperature),
+                ) {
+                    (Ok(liquid), Ok(compressed)) if compressed.density() > liquid.density() => {
+                        step * (liquid.density() / (compressed.density() - liquid.density()))
+                    }
+                    // Close to the critical point, the compressed state may be outside of the liquid region.
+                    _ => bulk_modulus_by_temperature(temperature),
+                }
+            }
+        }
+    }
+
+    /// The saturation pressure at the given temperature.
+    /// Above the critical point, this is the critical pressure.
+    pub fn saturation_pressure(self, temperature: ThermodynamicTemperature) -> Pressure {
+        match self {
+            PropertyBackend::Approximate => saturation_pressure_by_temperature(temperature),
+            PropertyBackend::IapwsIf97 => {
+                let temperature = clamp_iapws_if97_temperature(temperature)
+                    .min(iapws_if97::critical_temperature());
+                iapws_if97::saturation_pressure(temperature)
+                    .unwrap_or_else(|_| saturation_pressure_by_temperature(temperature))
+            }
+        }
+    }
+
+    /// The boiling point at the given pressure.
+    pub fn boiling_point(self, pressure: Pressure) -> ThermodynamicTemperature {
+        match self {
+            PropertyBackend::Approximate => boiling_point_by_pressure(pressure),
+            PropertyBackend::IapwsIf97 => {
+                let pressure = pressure
+                    .max(self.saturation_pressure(ThermodynamicTemperature::new::<kelvin>(273.15)))
+                    .min(iapws_if97::critical_pressure());
+                iapws_if97::saturation_temperature(pressure)
+                    .unwrap_or_else(|_| boiling_point_by_pressure(pressure))
+            }
+        }
+    }
+
+    /// The pressure excerted by the given mass of steam at the given temperature in the given volume.
+    ///
+    /// Steam that is denser than saturated vapour is treated as supersaturated,
+    /// with the pressure rising proportionally to the density above the saturation pressure.
+    ///
+    /// The approximate backend uses the given equation of state.
+    /// With IAPWS-IF97, the equation of state is ignored, and outside of the range of validity, the ideal gas law is used.
+    ///
+    /// Above the critical temperature, the fluid is far from an ideal gas, so IAPWS-IF97 is used with both backends.
+    pub fn vapour_pressure(
+        self,
+        equation_of_state: SteamEquationOfState,
+        mass: Mass,
+        temperature: ThermodynamicTemperature,
+        volume: Volume,
+    ) -> Pressure {
+        // ideal gas law pV = mR'T, where R' is R scaled for the molar mass of water.
+        // R' = R / 0.018015kg/mol
+        let ideal_gas_pressure = mass * SPECIAL_IDEAL_GAS_CONSTANT * temperature / volume;
+
+        match self {
+            PropertyBackend::Approximate if temperature < iapws_if97::critical_temperature() => {
+                let density = mass / volume;
+                let saturation_pressure = self.saturation_pressure(temperature);
+                let saturated_vapour_density =
+                    equation_of_state.density(saturation_pressure, temperature);
+                if density >= saturated_vapour_density {
+                    saturation_pressure * (density / saturated_vapour_density)
+                } else {
+                    equation_of_state.pressure(density, temperature)
+                }
+            }
+            PropertyBackend::Approximate | PropertyBackend::IapwsIf97 => {
+                if mass.is_zero() {
+                    return Pressure::zero();
+                }
+
+                let density = mass / volume;
+                let temperature = clamp_iapws_if97_temperature(temperature);
+                if temperature < iapws_if97::critical_temperature() {
+                    if let Ok(saturated_vapour) = iapws_if97::saturated_vapour(temperature) {
+                        if density >= saturated_vapour.density() {
+                            return saturated_vapour.pressure()
+                                * (density / saturated_vapour.density());
+                        }
+                    }
+                }
+
+                iapws_if97::properties_by_density_temperature(density, temperature)
+                    .map(|properties| properties.pressure())
+                    .unwrap_or(ideal_gas_pressure)
+            }
+        }
+    }
+
+    /// The compressibility factor Z = p / (rho R' T) of steam at the given pressure and temperature.
+    /// Above the saturation pressure, this is the compressibility factor of saturated vapour, see [`PropertyBackend::vapour_pressure`].
+    pub fn vapour_compressibility_factor(
+        self,
+        equation_of_state: SteamEquationOfState,
+        pressure: Pressure,
+        temperature: ThermodynamicTemperature,
+    ) -> Ratio {
+        match self {
+            PropertyBackend::Approximate if temperature < iapws_if97::critical_temperature() => {
+                let pressure = pressure.min(self.saturation_pressure(temperature));
+                equation_of_state.compressibility_factor(pressure, temperature)
+            }
+            PropertyBackend::Approximate | PropertyBackend::IapwsIf97 => {
+                let temperature = clamp_iapws_if97_temperature(temperature);
+                let vapour = if temperature < iapws_if97::critical_temperature()
+                    && pressure >= self.saturation_pressure(temperature)
+                {
+                    iapws_if97::saturated_vapour(temperature)
+                } else {
+                    iapws_if97::properties(pressure, temperature)
+                };
+                match vapour {
+                    Ok(vapour) if !pressure.is_zero() => {
+                        vapour.pressure()
+                            / (vapour.density() * SPECIAL_IDEAL_GAS_CONSTANT * temperature)
+                    }
+                    _ => Ratio::new::<ratio>(1.0),
+                }
+            }
+        }
+    }
+}
+
+fn clamp_iapws_if97_temperature(temperature: ThermodynamicTemperature) -> ThermodynamicTemperature {
+    temperature
+        .max(ThermodynamicTemperature::new::<kelvin>(273.15))
+        .min(ThermodynamicTemperature::new::<kelvin>(2273.15))
+}
+
 mod constants {
     use lazy_static::lazy_static;
 
     // boiling point at low pressure https://www.myengineeringtools.com/Data_Diagrams/Water_Boiling_Point_Vs_Pressure.html
     // boiling point at high pressure https://www.engineeringtoolbox.com/water-vapor-saturation-pressure-d_599.html
-    use crate::interpolation_table::{LimitBehaviour, LinearInterpolationTable};
+    use crate::interpolation_table::grid::GridInterpolationKind;
+    use crate::interpolation_table::quantity::{QuantityGrid, QuantityTable};
+    use crate::interpolation_table::LimitBehaviour;
+    use uom::si::available_energy::kilojoule_per_kilogram;
+    use uom::si::f64::{AvailableEnergy, MassDensity, Pressure, ThermodynamicTemperature};
+    use uom::si::mass_density::gram_per_cubic_centimeter;
+    use uom::si::pressure::{bar, gigapascal, millibar};
+    use uom::si::thermodynamic_temperature::degree_celsius;
+
+    /// The highest temperature in Kelvin covered by the tables.
+    pub const MAXIMUM_TEMPERATURE: f64 = 2273.15;
+
+    /// The latent heat in kJ/kg at the triple point, which is the highest latent heat.
+    pub const MAXIMUM_LATENT_HEAT: f64 = 2500.9;
 
     lazy_static! {
+        /// (Celsius, bar) -> g/cm^3 for liquid water, computed from IAPWS-IF97.
+        /// Below the saturation pressure, this is the density of saturated liquid.
+        pub static ref DENSITY_BY_TEMPERATURE_PRESSURE: QuantityGrid<ThermodynamicTemperature, Pressure, MassDensity> =
+            QuantityGrid::from_units(
+                LimitBehaviour::Clamp,
+                ThermodynamicTemperature::new::<degree_celsius>,
+                Pressure::new::<bar>,
+                MassDensity::new::<gram_per_cubic_centimeter>,
+                vec![0.01, 25.0, 50.0, 75.0, 100.0, 125.0, 150.0, 175.0, 200.0, 225.0, 250.0, 275.0, 300.0, 325.0, 350.0],
+                vec![1.0, 50.0, 100.0, 150.0, 200.0, 250.0],
+                vec![
+                    vec![0.9998, 1.0023, 1.0048, 1.0073, 1.0097, 1.0122],
+                    vec![0.9970, 0.9992, 1.0015, 1.0037, 1.0058, 1.0080],
+                    vec![0.9880, 0.9902, 0.9923, 0.9944, 0.9965, 0.9986],
+                    vec![0.9749, 0.9770, 0.9792, 0.9813, 0.9835, 0.9856],
+                    vec![0.9584, 0.9606, 0.9629, 0.9652, 0.9674, 0.9696],
+                    vec![0.9390, 0.9414, 0.9439, 0.9464, 0.9488, 0.9512],
+                    vec![0.9170, 0.9196, 0.9223, 0.9250, 0.9277, 0.9303],
+                    vec![0.8923, 0.8949, 0.8981, 0.9011, 0.9041, 0.9071],
+                    vec![0.8647, 0.8673, 0.8709, 0.8745, 0.8780, 0.8813],
+                    vec![0.8338, 0.8360, 0.8404, 0.8447, 0.8488, 0.8528],
+                    vec![0.7989, 0.8001, 0.8057, 0.8110, 0.8161, 0.8209],
+                    vec![0.7590, 0.7590, 0.7651, 0.7721, 0.7787, 0.7848],
+                    vec![0.7121, 0.7121, 0.7153, 0.7256, 0.7347, 0.7430],
+                    vec![0.6543, 0.6543, 0.6543, 0.6649, 0.6798, 0.6924],
+                    vec![0.5747, 0.5747, 0.5747, 0.5747, 0.6006, 0.6255],
+                ],
+            )
+            .with_interpolation_kind(GridInterpolationKind::Bicubic)
+            .with_name("water density by temperature and pressure");
+
         /// Celsius -> g/cm^3
-        /// High temperatures (above 100) roughly at boiling pressure
-        pub static ref DENSITY_BY_TEMPERATURE: LinearInterpolationTable =
-            LinearInterpolationTable::new(LimitBehaviour::Clamp, vec![
+        /// High temperatures (above 100) roughly at boiling pressure, see [`DENSITY_BY_TEMPERATURE_PRESSURE`] for other pressures.
+        pub static ref DENSITY_BY_TEMPERATURE: QuantityTable<ThermodynamicTemperature, MassDensity> =
+            QuantityTable::from_units(LimitBehaviour::Clamp, ThermodynamicTemperature::new::<degree_celsius>, MassDensity::new::<gram_per_cubic_centimeter>, vec![
                 (0.0, 0.9998395),
                 (3.984, 0.999972),
                 (4.0, 0.999972),
@@ -288,10 +1063,11 @@ mod constants {
                 (900.0, 0.0418),
                 (1000.0, 0.0381),
                 (2000.0, 0.02085),
-            ]);
+            ])
+            .with_name("water density by temperature");
 
         /// mbar -> Celsius
-        /// above the critical point, we treat it as liquid
+        /// ends at the critical point, above which there is no phase change
         pub static ref BOILING_POINT_BY_PRESSURE_RAW: Vec<(f64, f64)> = vec![
             (0.003, -68.0),
             (0.017, -57.0),
@@ -343,50 +1119,330 @@ mod constants {
             (146010.0, 340.0),
             (186660.0, 360.0),
             (210440.0, 370.0),
-            (210441.0, 1e50),
+            (220640.0, 373.946),
+        ];
+
+        /// Celsius -> kJ/kg
+        /// Specific enthalpy of saturated liquid water computed with IAPWS-IF97.
+        /// Above the critical point, it follows the critical isobar.
+        /// Below the melting point, water freezes, see the table for ice.
+        pub static ref LIQUID_SPECIFIC_ENTHALPY_BY_TEMPERATURE_RAW: Vec<(f64, f64)> = vec![
+            (0.0, -0.04),
+            (0.01, 0.0),
+            (10.0, 42.02),
+            (20.0, 83.92),
+            (30.0, 125.75),
+            (40.0, 167.54),
+            (50.0, 209.34),
+            (60.0, 251.15),
+            (70.0, 293.02),
+            (80.0, 334.95),
+            (90.0, 376.97),
+            (100.0, 419.10),
+            (110.0, 461.36),
+            (120.0, 503.78),
+            (130.0, 546.39),
+            (140.0, 589.20),
+            (150.0, 632.25),
+            (160.0, 675.57),
+            (170.0, 719.21),
+            (180.0, 763.19),
+            (190.0, 807.57),
+            (200.0, 852.39),
+            (210.0, 897.73),
+            (220.0, 943.64),
+            (230.0, 990.21),
+            (240.0, 1037.52),
+            (250.0, 1085.69),
+            (260.0, 1134.83),
+            (270.0, 1185.09),
+            (280.0, 1236.67),
+            (290.0, 1289.80),
+            (300.0, 1344.77),
+            (310.0, 1402.00),
+            (320.0, 1462.05),
+            (330.0, 1525.74),
+            (340.0, 1594.45),
+            (350.0, 1670.86),
+            (360.0, 1761.49),
+            (370.0, 1892.64),
+            (373.946, 2087.55),
+            (375.0, 2334.95),
+            (380.0, 2497.86),
+            (385.0, 2579.37),
+            (390.0, 2639.87),
+            (400.0, 2732.92),
+            (420.0, 2867.92),
+            (450.0, 3017.65),
+            (500.0, 3210.82),
+            (600.0, 3520.60),
+            (700.0, 3795.08),
+            (800.0, 4057.94),
+            (1000.0, 4578.56),
+            (1500.0, 5932.01),
+            (2000.0, 7371.65),
         ];
 
-        pub static ref BOILING_POINT_BY_PRESSURE: LinearInterpolationTable = LinearInterpolationTable::new(LimitBehaviour::Clamp, BOILING_POINT_BY_PRESSURE_RAW.clone());
+        pub static ref LIQUID_SPECIFIC_ENTHALPY_BY_TEMPERATURE: QuantityTable<ThermodynamicTemperature, AvailableEnergy> = QuantityTable::from_units(LimitBehaviour::Clamp, ThermodynamicTemperature::new::<degree_celsius>, AvailableEnergy::new::<kilojoule_per_kilogram>, LIQUID_SPECIFIC_ENTHALPY_BY_TEMPERATURE_RAW.iter().copied()).with_name("water liquid specific enthalpy by temperature");
 
-        pub static ref SATURATION_PRESSURE_BY_TEMPERATURE: LinearInterpolationTable = LinearInterpolationTable::new(LimitBehaviour::Clamp, BOILING_POINT_BY_PRESSURE_RAW.iter().copied().map(|(pressure, temperature)| (temperature, pressure)).collect());
+        /// Celsius -> kJ/kg
+        /// Ice at one atmosphere, relative to the same reference as the liquid.
+        /// The heat capacity of ice is roughly proportional to the absolute temperature, reaching 2.1 kJ/kgK at the melting point.
+        pub static ref ICE_SPECIFIC_ENTHALPY_BY_TEMPERATURE_RAW: Vec<(f64, f64)> = vec![
+            (-273.15, -621.96),
+            (-200.0, -601.3),
+            (-150.0, -563.34),
+            (-100.0, -506.09),
+            (-50.0, -429.5),
+            (-25.0, -383.96),
+            (0.0, -333.59),
+        ];
+
+        pub static ref ICE_SPECIFIC_ENTHALPY_BY_TEMPERATURE: QuantityTable<ThermodynamicTemperature, AvailableEnergy> = QuantityTable::from_units(LimitBehaviour::Clamp, ThermodynamicTemperature::new::<degree_celsius>, AvailableEnergy::new::<kilojoule_per_kilogram>, ICE_SPECIFIC_ENTHALPY_BY_TEMPERATURE_RAW.iter().copied()).with_name("water ice specific enthalpy by temperature");
+
+        /// Includes ice, such that the temperature stays at the melting point while the water freezes.
+        pub static ref LIQUID_TEMPERATURE_BY_SPECIFIC_ENTHALPY: QuantityTable<AvailableEnergy, ThermodynamicTemperature> = QuantityTable::from_units(LimitBehaviour::Clamp, AvailableEnergy::new::<kilojoule_per_kilogram>, ThermodynamicTemperature::new::<degree_celsius>, ICE_SPECIFIC_ENTHALPY_BY_TEMPERATURE_RAW.iter().chain(LIQUID_SPECIFIC_ENTHALPY_BY_TEMPERATURE_RAW.iter()).copied().map(|(temperature, specific_enthalpy)| (specific_enthalpy, temperature))).with_name("water liquid temperature by specific enthalpy");
+
+        /// Celsius -> kJ/kg
+        /// Specific enthalpy of steam as ideal gas computed with IAPWS-IF97.
+        /// Below the triple point, the heat capacity is assumed to be constant.
+        pub static ref VAPOUR_SPECIFIC_ENTHALPY_BY_TEMPERATURE_RAW: Vec<(f64, f64)> = vec![
+            (-273.15, 1993.67),
+            (0.01, 2501.44),
+            (25.0, 2547.96),
+            (50.0, 2594.66),
+            (100.0, 2688.66),
+            (150.0, 2783.72),
+            (200.0, 2880.04),
+            (250.0, 2977.77),
+            (300.0, 3076.98),
+            (350.0, 3177.73),
+            (400.0, 3280.09),
+            (450.0, 3384.09),
+            (500.0, 3489.78),
+            (550.0, 3597.19),
+            (600.0, 3706.35),
+            (650.0, 3817.27),
+            (700.0, 3929.97),
+            (750.0, 4044.43),
+            (800.0, 4160.66),
+            (900.0, 4398.42),
+            (1000.0, 4642.83),
+            (1200.0, 5150.69),
+            (1400.0, 5681.30),
+            (1600.0, 6231.18),
+            (1800.0, 6797.19),
+            (2000.0, 7376.98),
+        ];
+
+        pub static ref VAPOUR_SPECIFIC_ENTHALPY_BY_TEMPERATURE: QuantityTable<ThermodynamicTemperature, AvailableEnergy> = QuantityTable::from_units(LimitBehaviour::Clamp, ThermodynamicTemperature::new::<degree_celsius>, AvailableEnergy::new::<kilojoule_per_kilogram>, VAPOUR_SPECIFIC_ENTHALPY_BY_TEMPERATURE_RAW.iter().copied()).with_name("water vapour specific enthalpy by temperature");
+
+        pub static ref VAPOUR_TEMPERATURE_BY_SPECIFIC_ENTHALPY: QuantityTable<AvailableEnergy, ThermodynamicTemperature> = QuantityTable::from_units(LimitBehaviour::Clamp, AvailableEnergy::new::<kilojoule_per_kilogram>, ThermodynamicTemperature::new::<degree_celsius>, VAPOUR_SPECIFIC_ENTHALPY_BY_TEMPERATURE_RAW.iter().copied().map(|(temperature, specific_enthalpy)| (specific_enthalpy, temperature))).with_name("water vapour temperature by specific enthalpy");
+
+        /// Celsius -> GPa
+        /// Isothermal bulk modulus of liquid water at saturation pressure, computed from IAPWS-IF97.
+        /// It vanishes at the critical point.
+        pub static ref BULK_MODULUS_BY_TEMPERATURE: QuantityTable<ThermodynamicTemperature, Pressure> =
+            QuantityTable::from_units(LimitBehaviour::Clamp, ThermodynamicTemperature::new::<degree_celsius>, Pressure::new::<gigapascal>, vec![
+                (0.01, 1.965),
+                (10.0, 2.0927),
+                (20.0, 2.1824),
+                (30.0, 2.2394),
+                (40.0, 2.2678),
+                (50.0, 2.2720),
+                (60.0, 2.2555),
+                (80.0, 2.1737),
+                (100.0, 2.0446),
+                (125.0, 1.8417),
+                (150.0, 1.6127),
+                (175.0, 1.3724),
+                (200.0, 1.1318),
+                (225.0, 0.8996),
+                (250.0, 0.6828),
+                (275.0, 0.4863),
+                (300.0, 0.3132),
+                (310.0, 0.2521),
+                (320.0, 0.1963),
+                (330.0, 0.1457),
+                (340.0, 0.0996),
+                (350.0, 0.0602),
+                (360.0, 0.0280),
+                (365.0, 0.0153),
+                (370.0, 0.0056),
+                (373.946, 0.0),
+            ])
+            .with_name("water bulk modulus by temperature");
+
+        /// Celsius -> kJ/kg
+        /// Latent heat at saturation, computed from IAPWS-IF97.
+        /// It vanishes at the critical point.
+        pub static ref LATENT_HEAT_BY_TEMPERATURE: QuantityTable<ThermodynamicTemperature, AvailableEnergy> =
+            QuantityTable::from_units(LimitBehaviour::Clamp, ThermodynamicTemperature::new::<degree_celsius>, AvailableEnergy::new::<kilojoule_per_kilogram>, vec![
+                (0.01, MAXIMUM_LATENT_HEAT),
+                (25.0, 2441.71),
+                (50.0, 2381.97),
+                (75.0, 2320.63),
+                (100.0, 2256.47),
+                (125.0, 2188.04),
+                (150.0, 2113.67),
+                (175.0, 2031.55),
+                (200.0, 1939.67),
+                (225.0, 1835.42),
+                (250.0, 1715.33),
+                (275.0, 1574.44),
+                (300.0, 1404.80),
+                (310.0, 1325.92),
+                (320.0, 1238.62),
+                (330.0, 1140.51),
+                (340.0, 1027.62),
+                (350.0, 892.73),
+                (355.0, 812.74),
+                (360.0, 719.50),
+                (365.0, 604.41),
+                (370.0, 440.86),
+                (372.0, 336.15),
+                (373.0, 253.42),
+                (373.5, 186.19),
+                (373.9, 65.92),
+                (373.946, 0.0),
+            ])
+            .with_name("water latent heat by temperature");
+
+        pub static ref BOILING_POINT_BY_PRESSURE: QuantityTable<Pressure, ThermodynamicTemperature> = QuantityTable::from_units(LimitBehaviour::Clamp, Pressure::new::<millibar>, ThermodynamicTemperature::new::<degree_celsius>, BOILING_POINT_BY_PRESSURE_RAW.iter().copied()).with_name("water boiling point by pressure");
+
+        pub static ref SATURATION_PRESSURE_BY_TEMPERATURE: QuantityTable<ThermodynamicTemperature, Pressure> = QuantityTable::from_units(LimitBehaviour::Clamp, ThermodynamicTemperature::new::<degree_celsius>, Pressure::new::<millibar>, BOILING_POINT_BY_PRESSURE_RAW.iter().copied().map(|(pressure, temperature)| (temperature, pressure))).with_name("water saturation pressure by temperature");
     }
 }
 
 fn density_by_temperature(temperature: ThermodynamicTemperature) -> MassDensity {
-    let temperature = temperature.get::<degree_celsius>();
-    let density = constants::DENSITY_BY_TEMPERATURE.get(temperature);
-    MassDensity::new::<gram_per_cubic_centimeter>(density)
+    constants::DENSITY_BY_TEMPERATURE.get(temperature)
+}
+
+fn density_by_temperature_pressure(
+    temperature: ThermodynamicTemperature,
+    pressure: Pressure,
+) -> MassDensity {
+    constants::DENSITY_BY_TEMPERATURE_PRESSURE.get(temperature, pressure)
+}
+
+fn bulk_modulus_by_temperature(temperature: ThermodynamicTemperature) -> Pressure {
+    constants::BULK_MODULUS_BY_TEMPERATURE.get(temperature)
 }
 
 pub fn boiling_point_by_pressure(pressure: Pressure) -> ThermodynamicTemperature {
-    let pressure = pressure.get::<millibar>();
-    let temperature = constants::BOILING_POINT_BY_PRESSURE.get(pressure);
-    ThermodynamicTemperature::new::<degree_celsius>(temperature)
+    constants::BOILING_POINT_BY_PRESSURE.get(pressure)
 }
 
 fn saturation_pressure_by_temperature(temperature: ThermodynamicTemperature) -> Pressure {
-    let temperature = temperature.get::<degree_celsius>();
-    let pressure = constants::SATURATION_PRESSURE_BY_TEMPERATURE.get(temperature);
-    Pressure::new::<millibar>(pressure)
+    constants::SATURATION_PRESSURE_BY_TEMPERATURE.get(temperature)
 }
 
-#[allow(clippy::type_complexity)]
-pub const SPECIAL_IDEAL_GAS_CONSTANT: Quantity<ISQ<P2, Z0, N2, Z0, N1, Z0, Z0>, SI<f64>, f64> =
-    Quantity {
-        dimension: PhantomData,
-        units: PhantomData,
-        value: 461.5,
-    };
+/// The specific enthalpy of liquid water, or of ice below the melting point.
+fn liquid_specific_enthalpy(temperature: ThermodynamicTemperature) -> AvailableEnergy {
+    liquid_table(temperature).get(temperature)
+}
+
+fn liquid_temperature(specific_enthalpy: AvailableEnergy) -> ThermodynamicTemperature {
+    constants::LIQUID_TEMPERATURE_BY_SPECIFIC_ENTHALPY.get(specific_enthalpy)
+}
+
+fn vapour_specific_enthalpy(temperature: ThermodynamicTemperature) -> AvailableEnergy {
+    constants::VAPOUR_SPECIFIC_ENTHALPY_BY_TEMPERATURE.get(temperature)
+}
+
+fn vapour_temperature(specific_enthalpy: AvailableEnergy) -> ThermodynamicTemperature {
+    constants::VAPOUR_TEMPERATURE_BY_SPECIFIC_ENTHALPY.get(specific_enthalpy)
+}
+
+pub const SPECIAL_IDEAL_GAS_CONSTANT: SpecificHeatCapacity = Quantity {
+    dimension: PhantomData,
+    units: PhantomData,
+    value: 461.5,
+};
+
+/// The heat capacity of liquid water at the given temperature.
+/// Below the melting point, this is the heat capacity of ice.
+pub fn liquid_heat_capacity(temperature: ThermodynamicTemperature) -> SpecificHeatCapacity {
+    heat_capacity_by_table(liquid_table(temperature), temperature)
+}
+
+/// The table of specific enthalpy by temperature for liquid water or ice.
+fn liquid_table(
+    temperature: ThermodynamicTemperature,
+) -> &'static QuantityTable<ThermodynamicTemperature, AvailableEnergy> {
+    if temperature < melting_point() {
+        &constants::ICE_SPECIFIC_ENTHALPY_BY_TEMPERATURE
+    } else {
+        &constants::LIQUID_SPECIFIC_ENTHALPY_BY_TEMPERATURE
+    }
+}
+
+/// The isobaric heat capacity of steam at the given temperature.
+/// Steam is treated as ideal gas here.
+pub fn vapour_heat_capacity(temperature: ThermodynamicTemperature) -> SpecificHeatCapacity {
+    heat_capacity_by_table(
+        &constants::VAPOUR_SPECIFIC_ENTHALPY_BY_TEMPERATURE,
+        temperature,
+    )
+}
+
+fn heat_capacity_by_table(
+    specific_enthalpy_by_temperature: &QuantityTable<ThermodynamicTemperature, AvailableEnergy>,
+    temperature: ThermodynamicTemperature,
+) -> SpecificHeatCapacity {
+    let step = TemperatureInterval::new::<temperature_interval::kelvin>(0.01);
+    (specific_enthalpy_by_temperature.get(temperature + step)
+        - specific_enthalpy_by_temperature.get(temperature - step))
+        / (2.0 * step)
+}
+
+/// The factors by which the concentration of a solute changes in the remaining water and in the steam,
+/// when the given fraction of the water evaporates.
+/// The partition coefficient is the ratio of the concentration in steam to the concentration in water,
+/// and the solute is assumed to stay in equilibrium during evaporation, as in a Rayleigh distillation.
+fn partition_by_evaporation(evaporated_fraction: f64, partition_coefficient: f64) -> (f64, f64) {
+    assert!((0.0..=1.0).contains(&evaporated_fraction));
+    if evaporated_fraction == 0.0 {
+        return (1.0, partition_coefficient);
+    }
+
+    let remaining_fraction = 1.0 - evaporated_fraction;
+    if remaining_fraction == 0.0 {
+        // There is no water left to hold the solute, so the steam carries all of it.
+        return (1.0, 1.0 / evaporated_fraction);
+    }
+
+    let remaining_solute = remaining_fraction.powf(partition_coefficient);
+    (
+        remaining_solute / remaining_fraction,
+        (1.0 - remaining_solute) / evaporated_fraction,
+    )
+}
+
+/// The temperature above which water does not evaporate or condensate.
+pub fn critical_temperature() -> ThermodynamicTemperature {
+    iapws_if97::critical_temperature()
+}
+
+/// The energy required to evaporate water at the given saturation temperature, and set free by condensing water.
+/// It vanishes at the critical point.
+pub fn phase_change_energy(temperature: ThermodynamicTemperature) -> AvailableEnergy {
+    constants::LATENT_HEAT_BY_TEMPERATURE.get(temperature)
+}
+
+/// The temperature at which ice melts and water freezes at one atmosphere.
+pub fn melting_point() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<degree_celsius>(0.0)
+}
 
-/// The heat capacity of water.
-/// We treat it as the same over all temperatures and phases to avoid creating or losing energy due to moving boiling point.
-pub fn heat_capacity() -> SpecificHeatCapacity {
-    SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4180.0)
+/// The energy required to melt ice, and set free by freezing water.
+pub fn fusion_energy() -> AvailableEnergy {
+    AvailableEnergy::new::<kilojoule_per_kilogram>(333.55)
 }
 
-/// The energy required to evaporate water, and set free by condensing water.
-pub fn phase_change_energy() -> AvailableEnergy {
-    AvailableEnergy::new::<joule_per_gram>(2230.0)
+/// The density of ice at the melting point.
+pub fn ice_density() -> MassDensity {
+    MassDensity::new::<gram_per_cubic_centimeter>(0.9167)
 }
 
 /// The heat transfer coefficient between steam and water without a separating surface.
@@ -0,0 +1,165 @@
+//! The chemistry of reactor coolant water.
+//!
+//! pH and conductivity are computed at 25°C, as measured on a cooled sample.
+//! Limiting molar conductivities are taken from the CRC Handbook of Chemistry and Physics.
+
+use uom::num_traits::Zero;
+use uom::si::electrical_conductivity::siemens_per_meter;
+use uom::si::f64::{ElectricalConductivity, Ratio};
+use uom::si::ratio::ratio;
+
+use crate::substance::water::partition_by_evaporation;
+
+#[cfg(test)]
+mod tests;
+
+/// Ion product of water at 25°C in (mol/l)^2.
+const WATER_ION_PRODUCT: f64 = 1.008e-14;
+/// Acid dissociation constant of boric acid at 25°C in mol/l.
+const BORIC_ACID_DISSOCIATION_CONSTANT: f64 = 5.75e-10;
+
+// Molar masses in g/mol.
+const LITHIUM_MOLAR_MASS: f64 = 6.94;
+const CHLORIDE_MOLAR_MASS: f64 = 35.453;
+const BORON_MOLAR_MASS: f64 = 10.81;
+
+// Limiting molar conductivities at 25°C in S cm^2/mol.
+const HYDROGEN_ION_CONDUCTIVITY: f64 = 349.65;
+const HYDROXIDE_CONDUCTIVITY: f64 = 198.0;
+const LITHIUM_CONDUCTIVITY: f64 = 38.66;
+const SODIUM_CONDUCTIVITY: f64 = 50.08;
+const CHLORIDE_CONDUCTIVITY: f64 = 76.31;
+const BORATE_CONDUCTIVITY: f64 = 32.0;
+
+// Ratio of the concentration in steam to the concentration in water for dissolved gases.
+// These are rough values for typical operating temperatures.
+const OXYGEN_PARTITION_COEFFICIENT: f64 = 300.0;
+const HYDROGEN_PARTITION_COEFFICIENT: f64 = 400.0;
+
+/// The concentrations of chemicals dissolved in some water, by mass.
+///
+/// Dissolved boron is tracked separately by [`super::Water`], but taken into account for pH and conductivity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chemistry {
+    #[cfg_attr(feature = "serde", serde(rename = "dissolved_oxygen_kg_per_kg"))]
+    pub dissolved_oxygen: Ratio,
+    #[cfg_attr(feature = "serde", serde(rename = "dissolved_hydrogen_kg_per_kg"))]
+    pub dissolved_hydrogen: Ratio,
+    /// Lithium, dissolved as lithium hydroxide to raise the pH.
+    #[cfg_attr(feature = "serde", serde(rename = "lithium_kg_per_kg"))]
+    pub lithium: Ratio,
+    /// Chloride, assumed to enter as sodium chloride from seawater in-leakage.
+    /// Hence, it does not change the pH.
+    #[cfg_attr(feature = "serde", serde(rename = "chloride_kg_per_kg"))]
+    pub chloride: Ratio,
+}
+
+impl Chemistry {
+    /// Pure water.
+    pub fn zero() -> Self {
+        Self {
+            dissolved_oxygen: Ratio::zero(),
+            dissolved_hydrogen: Ratio::zero(),
+            lithium: Ratio::zero(),
+            chloride: Ratio::zero(),
+        }
+    }
+
+    /// The pH at 25°C, given the boron concentration by mass.
+    pub fn ph(&self, boron_concentration: Ratio) -> f64 {
+        -self.ions(boron_concentration).hydrogen.log10()
+    }
+
+    /// The electrical conductivity at 25°C, given the boron concentration by mass.
+    pub fn conductivity(&self, boron_concentration: Ratio) -> ElectricalConductivity {
+        let ions = self.ions(boron_concentration);
+        let chloride = molarity(self.chloride, CHLORIDE_MOLAR_MASS);
+
+        // S cm^2/mol * mol/l = S/cm * 1e-3
+        let conductivity = HYDROGEN_ION_CONDUCTIVITY * ions.hydrogen
+            + HYDROXIDE_CONDUCTIVITY * ions.hydroxide
+            + LITHIUM_CONDUCTIVITY * molarity(self.lithium, LITHIUM_MOLAR_MASS)
+            + (SODIUM_CONDUCTIVITY + CHLORIDE_CONDUCTIVITY) * chloride
+            + BORATE_CONDUCTIVITY * ions.borate;
+        ElectricalConductivity::new::<siemens_per_meter>(conductivity * 1e-3 * 100.0)
+    }
+
+    /// Mix two amounts of water with the given mass fractions.
+    pub(super) fn mix(self, self_fraction: f64, rhs: Self, rhs_fraction: f64) -> Self {
+        let mix = |lhs: Ratio, rhs: Ratio| lhs * self_fraction + rhs * rhs_fraction;
+        Self {
+            dissolved_oxygen: mix(self.dissolved_oxygen, rhs.dissolved_oxygen),
+            dissolved_hydrogen: mix(self.dissolved_hydrogen, rhs.dissolved_hydrogen),
+            lithium: mix(self.lithium, rhs.lithium),
+            chloride: mix(self.chloride, rhs.chloride),
+        }
+    }
+
+    /// Evaporate the given fraction of the mass of some water with this chemistry.
+    /// Returns the chemistry of the steam, and updates this to the chemistry of the remaining water.
+    ///
+    /// Dissolved gases are stripped into the steam, while everything else stays in the water.
+    pub(super) fn evaporate(&mut self, evaporated_fraction: Ratio) -> Self {
+        let evaporated_fraction = evaporated_fraction.get::<ratio>();
+        let mut steam = Self::zero();
+        for (concentration, steam_concentration, partition_coefficient) in [
+            (
+                &mut self.dissolved_oxygen,
+                &mut steam.dissolved_oxygen,
+                OXYGEN_PARTITION_COEFFICIENT,
+            ),
+            (
+                &mut self.dissolved_hydrogen,
+                &mut steam.dissolved_hydrogen,
+                HYDROGEN_PARTITION_COEFFICIENT,
+            ),
+            (&mut self.lithium, &mut steam.lithium, 0.0),
+            (&mut self.chloride, &mut steam.chloride, 0.0),
+        ] {
+            let (water_factor, steam_factor) =
+                partition_by_evaporation(evaporated_fraction, partition_coefficient);
+            *steam_concentration = *concentration * steam_factor;
+            *concentration *= water_factor;
+        }
+        steam
+    }
+
+    /// Solve the charge balance [Li+] + [H+] = [OH-] + [B(OH)4-] for the concentration of hydrogen ions.
+    fn ions(&self, boron_concentration: Ratio) -> Ions {
+        let lithium = molarity(self.lithium, LITHIUM_MOLAR_MASS);
+        let boron = molarity(boron_concentration, BORON_MOLAR_MASS);
+        let ions = |hydrogen: f64| Ions {
+            hydrogen,
+            hydroxide: WATER_ION_PRODUCT / hydrogen,
+            borate: boron * BORIC_ACID_DISSOCIATION_CONSTANT
+                / (BORIC_ACID_DISSOCIATION_CONSTANT + hydrogen),
+        };
+
+        // The charge excess is increasing in [H+], so bisect on its logarithm.
+        let mut lower = -15.0;
+        let mut upper = 1.0;
+        while upper - lower > 1e-10 {
+            let middle = (lower + upper) / 2.0;
+            let middle_ions = ions(10.0_f64.powf(middle));
+            if lithium + middle_ions.hydrogen < middle_ions.hydroxide + middle_ions.borate {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+        ions(10.0_f64.powf((lower + upper) / 2.0))
+    }
+}
+
+/// Concentrations in mol/l.
+struct Ions {
+    hydrogen: f64,
+    hydroxide: f64,
+    borate: f64,
+}
+
+/// The concentration in mol/l of a solute with the given mass fraction in dilute water at 25°C.
+fn molarity(concentration: Ratio, molar_mass: f64) -> f64 {
+    concentration.get::<ratio>() * 997.0 / molar_mass
+}
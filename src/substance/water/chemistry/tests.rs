@@ -0,0 +1,75 @@
+use uom::si::{
+    electrical_conductivity::siemens_per_meter,
+    f64::Ratio,
+    ratio::{part_per_billion, part_per_million, ratio},
+};
+
+use super::Chemistry;
+use uom::num_traits::Zero;
+
+/// Microsiemens per centimeter, the usual unit for water conductivity.
+fn microsiemens_per_centimeter(chemistry: &Chemistry, boron_concentration: Ratio) -> f64 {
+    chemistry
+        .conductivity(boron_concentration)
+        .get::<siemens_per_meter>()
+        * 1e4
+}
+
+#[test]
+fn pure_water() {
+    let chemistry = Chemistry::zero();
+    assert!((chemistry.ph(Ratio::zero()) - 7.0).abs() < 0.01);
+    assert!((microsiemens_per_centimeter(&chemistry, Ratio::zero()) - 0.055).abs() < 0.001);
+}
+
+#[test]
+fn boron_and_lithium() {
+    let boron = Ratio::new::<part_per_million>(1000.0);
+    let borated = Chemistry::zero();
+    let lithiated = Chemistry {
+        lithium: Ratio::new::<part_per_million>(2.0),
+        ..Chemistry::zero()
+    };
+
+    // Boric acid is a weak acid, and lithium hydroxide counters it.
+    assert!(borated.ph(boron) < 6.0);
+    assert!(lithiated.ph(boron) > borated.ph(boron));
+    assert!(lithiated.ph(Ratio::zero()) > 10.0);
+    assert!(
+        microsiemens_per_centimeter(&lithiated, boron)
+            > microsiemens_per_centimeter(&borated, boron)
+    );
+}
+
+#[test]
+fn evaporate() {
+    let mut chemistry = Chemistry {
+        dissolved_oxygen: Ratio::new::<part_per_billion>(100.0),
+        dissolved_hydrogen: Ratio::new::<part_per_million>(3.0),
+        lithium: Ratio::new::<part_per_million>(2.0),
+        chloride: Ratio::new::<part_per_billion>(50.0),
+    };
+    let original = chemistry;
+
+    let fraction = 0.1;
+    let steam = chemistry.evaporate(Ratio::new::<ratio>(fraction));
+
+    // Mass of the solutes is conserved.
+    let conserved = |concentration: fn(&Chemistry) -> Ratio| {
+        let before = concentration(&original).get::<ratio>();
+        let after = (concentration(&chemistry) * (1.0 - fraction)
+            + concentration(&steam) * fraction)
+            .get::<ratio>();
+        (before - after).abs() < before * 1e-12
+    };
+    assert!(conserved(|chemistry| chemistry.dissolved_oxygen));
+    assert!(conserved(|chemistry| chemistry.dissolved_hydrogen));
+    assert!(conserved(|chemistry| chemistry.lithium));
+    assert!(conserved(|chemistry| chemistry.chloride));
+
+    // The gases are stripped, while the salts are concentrated in the water.
+    assert!(chemistry.dissolved_oxygen < original.dissolved_oxygen * 1e-3);
+    assert!(chemistry.dissolved_hydrogen < original.dissolved_hydrogen * 1e-3);
+    assert!(chemistry.lithium > original.lithium);
+    assert_eq!(steam.lithium, Ratio::zero());
+}
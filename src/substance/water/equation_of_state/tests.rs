@@ -0,0 +1,66 @@
+use uom::si::{
+    f64::{MassDensity, Pressure, ThermodynamicTemperature},
+    mass_density::kilogram_per_cubic_meter,
+    pressure::bar,
+    ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
+};
+
+use super::SteamEquationOfState;
+
+#[test]
+fn saturated_steam() {
+    // Saturated steam from IAPWS-IF97 as (temperature, pressure, density).
+    for (temperature, pressure, density) in [
+        (100.0, 1.01418, 0.5904),
+        (200.0, 15.549, 7.864),
+        (250.0, 39.762, 19.99),
+        (300.0, 85.927, 46.17),
+        (350.0, 165.29, 113.6),
+    ] {
+        let temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature);
+        let density = MassDensity::new::<kilogram_per_cubic_meter>(density);
+
+        let ideal_gas = SteamEquationOfState::IdealGas
+            .pressure(density, temperature)
+            .get::<bar>();
+        let peng_robinson = SteamEquationOfState::PengRobinson
+            .pressure(density, temperature)
+            .get::<bar>();
+        assert!(
+            (peng_robinson - pressure).abs() < 0.035 * pressure,
+            "{peng_robinson}"
+        );
+        // At atmospheric pressure, steam is almost ideal, but at high pressures the ideal gas law is far off.
+        if pressure > 10.0 {
+            assert!((ideal_gas - pressure).abs() > 3.0 * (peng_robinson - pressure).abs());
+        }
+    }
+}
+
+#[test]
+fn density_is_inverse_of_pressure() {
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(280.0);
+    for equation_of_state in [
+        SteamEquationOfState::IdealGas,
+        SteamEquationOfState::PengRobinson,
+    ] {
+        for pressure in [0.1, 1.0, 10.0, 60.0] {
+            let pressure = Pressure::new::<bar>(pressure);
+            let density = equation_of_state.density(pressure, temperature);
+            let roundtrip = equation_of_state.pressure(density, temperature);
+            assert!(((roundtrip - pressure) / pressure).get::<ratio>().abs() < 1e-9);
+        }
+    }
+
+    assert_eq!(
+        SteamEquationOfState::IdealGas
+            .compressibility_factor(Pressure::new::<bar>(60.0), temperature)
+            .get::<ratio>(),
+        1.0
+    );
+    let compressibility_factor = SteamEquationOfState::PengRobinson
+        .compressibility_factor(Pressure::new::<bar>(60.0), temperature)
+        .get::<ratio>();
+    assert!(compressibility_factor > 0.7 && compressibility_factor < 0.9);
+}
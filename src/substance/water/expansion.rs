@@ -0,0 +1,94 @@
+//! Expansion of steam through turbine stages and nozzles.
+//!
+//! An ideal expansion keeps the specific entropy.
+//! A real one converts less enthalpy into work, which is described by the isentropic efficiency.
+
+use uom::si::f64::{AvailableEnergy, MassRate, Power, Pressure, Ratio, Time};
+use uom::si::ratio::ratio;
+
+use crate::error::{Error, Result};
+use crate::substance::water::flash::{self, State};
+use crate::synchronous_machine::SynchronousMachine;
+
+#[cfg(test)]
+mod tests;
+
+/// The result of expanding water from an inlet state to an outlet pressure.
+#[derive(Debug, Clone, Copy)]
+pub struct Expansion {
+    outlet: State,
+    isentropic_outlet: State,
+    enthalpy_drop: AvailableEnergy,
+}
+
+impl Expansion {
+    /// The state after the expansion.
+    pub fn outlet(&self) -> &State {
+        &self.outlet
+    }
+
+    /// The state after an ideal expansion to the same pressure.
+    pub fn isentropic_outlet(&self) -> &State {
+        &self.isentropic_outlet
+    }
+
+    /// The specific enthalpy converted into work.
+    /// It is negative for a compression.
+    pub fn enthalpy_drop(&self) -> AvailableEnergy {
+        self.enthalpy_drop
+    }
+
+    /// The shaft power for the given mass flow through the expansion.
+    pub fn shaft_power(&self, mass_flow: MassRate) -> Power {
+        self.enthalpy_drop * mass_flow
+    }
+
+    /// Drive a generator on the same shaft with the given mass flow through the expansion for a time step,
+    /// against the given electrical load, see [`SynchronousMachine::update`].
+    pub fn drive(
+        &self,
+        machine: &mut SynchronousMachine,
+        mass_flow: MassRate,
+        electrical_load: Power,
+        delta_time: Time,
+    ) {
+        machine.update(self.shaft_power(mass_flow), electrical_load, delta_time);
+    }
+}
+
+/// Expand water from the inlet state to the outlet pressure.
+///
+/// Without an isentropic efficiency, the expansion is ideal.
+/// Otherwise, only the given fraction of the isentropic enthalpy drop is converted into work, and the rest stays in the water.
+/// If the outlet pressure is above the inlet pressure, this is a compression, which takes the isentropic work divided by the efficiency.
+pub fn expand(
+    inlet: &State,
+    outlet_pressure: Pressure,
+    isentropic_efficiency: Option<Ratio>,
+) -> Result<Expansion> {
+    let isentropic_efficiency = isentropic_efficiency
+        .map(|efficiency| efficiency.get::<ratio>())
+        .unwrap_or(1.0);
+    if !(isentropic_efficiency > 0.0 && isentropic_efficiency <= 1.0) {
+        return Err(Error::InvalidIsentropicEfficiency {
+            efficiency: isentropic_efficiency,
+        });
+    }
+
+    let isentropic_outlet = flash::by_pressure_entropy(outlet_pressure, inlet.specific_entropy())?;
+    let isentropic_enthalpy_drop =
+        inlet.specific_enthalpy() - isentropic_outlet.specific_enthalpy();
+    let enthalpy_drop = if outlet_pressure <= inlet.pressure() {
+        isentropic_enthalpy_drop * isentropic_efficiency
+    } else {
+        isentropic_enthalpy_drop / isentropic_efficiency
+    };
+    let outlet =
+        flash::by_pressure_enthalpy(outlet_pressure, inlet.specific_enthalpy() - enthalpy_drop)?;
+
+    Ok(Expansion {
+        outlet,
+        isentropic_outlet,
+        enthalpy_drop,
+    })
+}
@@ -0,0 +1,144 @@
+use uom::si::{
+    angle::radian,
+    angular_velocity::radian_per_second,
+    available_energy::kilojoule_per_kilogram,
+    energy::megajoule,
+    f64::{
+        Angle, AngularVelocity, AvailableEnergy, MagneticFlux, MassRate, MomentOfInertia, Power,
+        Pressure, Ratio, Time,
+    },
+    magnetic_flux::weber,
+    mass_rate::kilogram_per_second,
+    moment_of_inertia::kilogram_square_meter,
+    power::megawatt,
+    pressure::bar,
+    ratio::ratio,
+    specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+    time::second,
+};
+
+use super::expand;
+use crate::substance::water::flash::{by_pressure_enthalpy, State};
+use crate::synchronous_machine::SynchronousMachine;
+
+fn assert_close(actual: f64, expected: f64, relative_tolerance: f64) {
+    assert!(
+        ((actual - expected) / expected).abs() < relative_tolerance,
+        "actual: {actual}, expected: {expected}"
+    );
+}
+
+/// Saturated steam at 70 bar with 2772.6 kJ/kg and 5.8148 kJ/(kg K).
+fn live_steam() -> State {
+    by_pressure_enthalpy(
+        Pressure::new::<bar>(70.0),
+        AvailableEnergy::new::<kilojoule_per_kilogram>(2772.6),
+    )
+    .unwrap()
+}
+
+#[test]
+fn turbine() {
+    let inlet = live_steam();
+    let condenser = Pressure::new::<bar>(0.05);
+
+    // At 0.05 bar, saturated liquid has 137.75 kJ/kg, and the latent heat is 2423.0 kJ/kg.
+    let ideal = expand(&inlet, condenser, None).unwrap();
+    assert_close(
+        ideal.outlet().quality().unwrap().get::<ratio>(),
+        0.6743,
+        2e-3,
+    );
+    assert_close(
+        ideal
+            .outlet()
+            .specific_entropy()
+            .get::<kilojoule_per_kilogram_kelvin>(),
+        inlet
+            .specific_entropy()
+            .get::<kilojoule_per_kilogram_kelvin>(),
+        1e-6,
+    );
+    let ideal_drop = ideal.enthalpy_drop().get::<kilojoule_per_kilogram>();
+    assert_close(ideal_drop, 2772.6 - 137.75 - 0.6743 * 2423.0, 5e-3);
+    assert_close(
+        ideal
+            .shaft_power(MassRate::new::<kilogram_per_second>(1000.0))
+            .get::<megawatt>(),
+        ideal_drop,
+        1e-9,
+    );
+
+    // A real turbine converts less of the enthalpy, leaving wetter steam with more entropy.
+    let real = expand(&inlet, condenser, Some(Ratio::new::<ratio>(0.85))).unwrap();
+    assert_close(
+        real.enthalpy_drop().get::<kilojoule_per_kilogram>(),
+        0.85 * ideal_drop,
+        1e-9,
+    );
+    assert!(real.outlet().quality().unwrap() > ideal.outlet().quality().unwrap());
+    assert!(real.outlet().specific_entropy() > inlet.specific_entropy());
+    assert_eq!(
+        real.isentropic_outlet().specific_enthalpy(),
+        ideal.outlet().specific_enthalpy()
+    );
+
+    assert!(expand(&inlet, condenser, Some(Ratio::new::<ratio>(0.0))).is_err());
+    assert!(expand(&inlet, condenser, Some(Ratio::new::<ratio>(1.1))).is_err());
+}
+
+#[test]
+fn generator() {
+    let expansion = expand(&live_steam(), Pressure::new::<bar>(0.05), None).unwrap();
+    let mass_flow = MassRate::new::<kilogram_per_second>(1000.0);
+    let shaft_power = expansion.shaft_power(mass_flow);
+    let mut machine = SynchronousMachine::new(
+        MomentOfInertia::new::<kilogram_square_meter>(1.0e5),
+        Angle::new::<radian>(0.0),
+        AngularVelocity::new::<radian_per_second>(314.0),
+        Power::new::<megawatt>(1500.0),
+        MagneticFlux::new::<weber>(10.0),
+        Power::new::<megawatt>(1.0),
+    );
+
+    // The shaft power that is not taken by the electrical load accelerates the machine.
+    let kinetic_energy = machine.signed_angular_kinetic_energy();
+    let electrical_load = Power::new::<megawatt>(500.0);
+    let delta_time = Time::new::<second>(0.1);
+    expansion.drive(&mut machine, mass_flow, electrical_load, delta_time);
+    assert!(machine.angular_velocity() > AngularVelocity::new::<radian_per_second>(314.0));
+    assert_close(
+        (machine.signed_angular_kinetic_energy() - kinetic_energy).get::<megajoule>(),
+        ((shaft_power - electrical_load) * delta_time).get::<megajoule>(),
+        1e-9,
+    );
+}
+
+#[test]
+fn pump() {
+    // Pumping condensate takes about v dp = 0.001005 m^3/kg * 6995 kPa.
+    let condensate = by_pressure_enthalpy(
+        Pressure::new::<bar>(0.05),
+        AvailableEnergy::new::<kilojoule_per_kilogram>(137.75),
+    )
+    .unwrap();
+    let ideal = expand(&condensate, Pressure::new::<bar>(70.0), None).unwrap();
+    assert_close(
+        ideal.enthalpy_drop().get::<kilojoule_per_kilogram>(),
+        -7.03,
+        1e-2,
+    );
+
+    let real = expand(
+        &condensate,
+        Pressure::new::<bar>(70.0),
+        Some(Ratio::new::<ratio>(0.8)),
+    )
+    .unwrap();
+    assert_close(
+        real.enthalpy_drop().get::<kilojoule_per_kilogram>(),
+        ideal.enthalpy_drop().get::<kilojoule_per_kilogram>() / 0.8,
+        1e-9,
+    );
+    assert!(real.outlet().temperature() > ideal.outlet().temperature());
+}
@@ -0,0 +1,249 @@
+//! Flash calculations, which find the full thermodynamic state of water from a pair of properties.
+//!
+//! Inside the two-phase region, the state is a mixture of saturated liquid and vapour.
+//! All properties are computed by [`iapws_if97`], so states outside of its range of validity are an error.
+
+use uom::si::f64::{
+    AvailableEnergy, MassDensity, Pressure, Ratio, SpecificHeatCapacity, SpecificVolume,
+    ThermodynamicTemperature,
+};
+use uom::si::{
+    available_energy::kilojoule_per_kilogram, ratio::ratio,
+    specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+    specific_volume::cubic_meter_per_kilogram, thermodynamic_temperature::kelvin,
+};
+
+use crate::error::{Error, Result};
+use crate::substance::water::iapws_if97::{self, Properties, Region};
+
+#[cfg(test)]
+mod tests;
+
+/// The thermodynamic state of some water, which may be a mixture of saturated liquid and vapour.
+#[derive(Debug, Clone, Copy)]
+pub struct State {
+    region: Region,
+    pressure: Pressure,
+    temperature: ThermodynamicTemperature,
+    density: MassDensity,
+    specific_enthalpy: AvailableEnergy,
+    specific_internal_energy: AvailableEnergy,
+    specific_entropy: SpecificHeatCapacity,
+    quality: Option<Ratio>,
+}
+
+impl State {
+    /// The region of IAPWS-IF97, which is [`Region::Region4`] for a mixture of saturated liquid and vapour.
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    pub fn density(&self) -> MassDensity {
+        self.density
+    }
+
+    pub fn specific_volume(&self) -> SpecificVolume {
+        1.0 / self.density
+    }
+
+    pub fn specific_enthalpy(&self) -> AvailableEnergy {
+        self.specific_enthalpy
+    }
+
+    pub fn specific_internal_energy(&self) -> AvailableEnergy {
+        self.specific_internal_energy
+    }
+
+    pub fn specific_entropy(&self) -> SpecificHeatCapacity {
+        self.specific_entropy
+    }
+
+    /// The mass fraction of vapour in the two-phase region.
+    /// Outside of it, the water is a single phase and this is `None`.
+    pub fn quality(&self) -> Option<Ratio> {
+        self.quality
+    }
+
+    /// A mixture of saturated liquid and vapour with the given quality.
+    fn saturated(liquid: &Properties, vapour: &Properties, quality: f64) -> Self {
+        let mix =
+            |liquid: AvailableEnergy, vapour: AvailableEnergy| liquid + (vapour - liquid) * quality;
+        Self {
+            region: Region::Region4,
+            pressure: liquid.pressure(),
+            temperature: liquid.temperature(),
+            density: 1.0 / (quality / vapour.density() + (1.0 - quality) / liquid.density()),
+            specific_enthalpy: mix(liquid.specific_enthalpy(), vapour.specific_enthalpy()),
+            specific_internal_energy: mix(
+                liquid.specific_internal_energy(),
+                vapour.specific_internal_energy(),
+            ),
+            specific_entropy: liquid.specific_entropy()
+                + (vapour.specific_entropy() - liquid.specific_entropy()) * quality,
+            quality: Some(Ratio::new::<ratio>(quality)),
+        }
+    }
+}
+
+impl From<Properties> for State {
+    fn from(properties: Properties) -> Self {
+        Self {
+            region: properties.region(),
+            pressure: properties.pressure(),
+            temperature: properties.temperature(),
+            density: properties.density(),
+            specific_enthalpy: properties.specific_enthalpy(),
+            specific_internal_energy: properties.specific_internal_energy(),
+            specific_entropy: properties.specific_entropy(),
+            quality: None,
+        }
+    }
+}
+
+/// The state of water with the given pressure and specific enthalpy, as after a throttling valve.
+pub fn by_pressure_enthalpy(
+    pressure: Pressure,
+    specific_enthalpy: AvailableEnergy,
+) -> Result<State> {
+    if let Some(state) = saturated_by_pressure(
+        pressure,
+        |properties| {
+            properties
+                .specific_enthalpy()
+                .get::<kilojoule_per_kilogram>()
+        },
+        specific_enthalpy.get::<kilojoule_per_kilogram>(),
+    )? {
+        return Ok(state);
+    }
+
+    let temperature = iapws_if97::temperature_by_pressure_enthalpy(pressure, specific_enthalpy)?;
+    iapws_if97::properties(pressure, temperature).map(State::from)
+}
+
+/// The state of water with the given pressure and specific entropy, as after an isentropic turbine stage.
+pub fn by_pressure_entropy(
+    pressure: Pressure,
+    specific_entropy: SpecificHeatCapacity,
+) -> Result<State> {
+    if let Some(state) = saturated_by_pressure(
+        pressure,
+        |properties| {
+            properties
+                .specific_entropy()
+                .get::<kilojoule_per_kilogram_kelvin>()
+        },
+        specific_entropy.get::<kilojoule_per_kilogram_kelvin>(),
+    )? {
+        return Ok(state);
+    }
+
+    let temperature = iapws_if97::temperature_by_pressure_entropy(pressure, specific_entropy)?;
+    iapws_if97::properties(pressure, temperature).map(State::from)
+}
+
+/// The state of water with the given temperature and specific volume.
+pub fn by_temperature_volume(
+    temperature: ThermodynamicTemperature,
+    specific_volume: SpecificVolume,
+) -> Result<State> {
+    let density = 1.0 / specific_volume;
+    if temperature < iapws_if97::critical_temperature() {
+        let liquid = iapws_if97::saturated_liquid(temperature)?;
+        let vapour = iapws_if97::saturated_vapour(temperature)?;
+        if density < liquid.density() && density > vapour.density() {
+            let quality = ((1.0 / density - 1.0 / liquid.density())
+                / (1.0 / vapour.density() - 1.0 / liquid.density()))
+            .get::<ratio>();
+            return Ok(State::saturated(&liquid, &vapour, quality));
+        }
+    }
+
+    iapws_if97::properties_by_density_temperature(density, temperature).map(State::from)
+}
+
+/// The state of water with the given specific internal energy and specific volume, as in a closed rigid container.
+///
+/// The internal energy rises with the temperature at constant volume, so the temperature is found by bisection.
+pub fn by_internal_energy_volume(
+    specific_internal_energy: AvailableEnergy,
+    specific_volume: SpecificVolume,
+) -> Result<State> {
+    let outside_range = || Error::OutsideIapwsIf97Range {
+        state: format!(
+            "u = {} kJ/kg, v = {} m^3/kg",
+            specific_internal_energy.get::<kilojoule_per_kilogram>(),
+            specific_volume.get::<cubic_meter_per_kilogram>()
+        ),
+    };
+    let state = |temperature: f64| {
+        by_temperature_volume(
+            ThermodynamicTemperature::new::<kelvin>(temperature),
+            specific_volume,
+        )
+    };
+
+    // Outside of the range of validity, the pressure is too high, which happens when the water is too hot.
+    let mut lower = 273.15;
+    let mut upper = 2273.15;
+    match state(lower) {
+        Ok(state) if state.specific_internal_energy() <= specific_internal_energy => {}
+        _ => return Err(outside_range()),
+    }
+    while upper - lower > 1e-9 {
+        let middle = (lower + upper) / 2.0;
+        match state(middle) {
+            Ok(state) if state.specific_internal_energy() < specific_internal_energy => {
+                lower = middle;
+            }
+            _ => upper = middle,
+        }
+    }
+
+    let state = state(lower).map_err(|_| outside_range())?;
+    let error = (state.specific_internal_energy() - specific_internal_energy)
+        .abs()
+        .get::<kilojoule_per_kilogram>();
+    if error > 1e-3 {
+        return Err(outside_range());
+    }
+    Ok(state)
+}
+
+/// The properties of saturated liquid and vapour at the given pressure, which has to be below the critical pressure.
+pub fn saturation(pressure: Pressure) -> Result<(Properties, Properties)> {
+    let temperature = iapws_if97::saturation_temperature(pressure)?;
+    Ok((
+        iapws_if97::saturated_liquid(temperature)?,
+        iapws_if97::saturated_vapour(temperature)?,
+    ))
+}
+
+/// The saturated mixture at the given pressure with the given value of a property that is linear in the quality.
+/// Returns `None` if the value is outside of the two-phase region, or the pressure is above the critical pressure.
+fn saturated_by_pressure(
+    pressure: Pressure,
+    property: impl Fn(&Properties) -> f64,
+    value: f64,
+) -> Result<Option<State>> {
+    if pressure >= iapws_if97::critical_pressure() {
+        return Ok(None);
+    }
+
+    let (liquid, vapour) = saturation(pressure)?;
+    let (liquid_value, vapour_value) = (property(&liquid), property(&vapour));
+    if (liquid_value..=vapour_value).contains(&value) {
+        let quality = (value - liquid_value) / (vapour_value - liquid_value);
+        Ok(Some(State::saturated(&liquid, &vapour, quality)))
+    } else {
+        Ok(None)
+    }
+}
@@ -0,0 +1,143 @@
+use uom::si::{
+    available_energy::kilojoule_per_kilogram,
+    f64::{AvailableEnergy, Pressure, SpecificHeatCapacity},
+    pressure::{bar, megapascal},
+    ratio::ratio,
+    specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+use super::{
+    by_internal_energy_volume, by_pressure_enthalpy, by_pressure_entropy, by_temperature_volume,
+};
+use crate::substance::water::iapws_if97::Region;
+
+fn assert_close(actual: f64, expected: f64, relative_tolerance: f64) {
+    assert!(
+        ((actual - expected) / expected).abs() < relative_tolerance,
+        "actual: {actual}, expected: {expected}"
+    );
+}
+
+#[test]
+fn pressure_enthalpy() {
+    // Half of the latent heat of 2257.5 kJ/kg above saturated liquid at 1 bar with 417.5 kJ/kg.
+    let state = by_pressure_enthalpy(
+        Pressure::new::<bar>(1.0),
+        AvailableEnergy::new::<kilojoule_per_kilogram>(1546.25),
+    )
+    .unwrap();
+    assert_eq!(state.region(), Region::Region4);
+    assert_close(state.quality().unwrap().get::<ratio>(), 0.5, 1e-3);
+    assert_close(state.temperature().get::<degree_celsius>(), 99.61, 1e-3);
+
+    // Compressed liquid from the verification table of region 1.
+    let state = by_pressure_enthalpy(
+        Pressure::new::<megapascal>(3.0),
+        AvailableEnergy::new::<kilojoule_per_kilogram>(115.331273),
+    )
+    .unwrap();
+    assert_eq!(state.region(), Region::Region1);
+    assert!(state.quality().is_none());
+    assert_close(state.temperature().get::<kelvin>(), 300.0, 1e-6);
+
+    assert!(by_pressure_enthalpy(
+        Pressure::new::<megapascal>(200.0),
+        AvailableEnergy::new::<kilojoule_per_kilogram>(1000.0),
+    )
+    .is_err());
+}
+
+#[test]
+fn pressure_entropy() {
+    // Saturated steam at 70 bar with 5.8148 kJ/(kg K) expanding isentropically to a condenser at 0.05 bar,
+    // where saturated liquid has 0.4763 kJ/(kg K) and saturated vapour 8.3938 kJ/(kg K).
+    let state = by_pressure_entropy(
+        Pressure::new::<bar>(0.05),
+        SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(5.8148),
+    )
+    .unwrap();
+    assert_close(state.quality().unwrap().get::<ratio>(), 0.6743, 1e-3);
+    assert_close(state.temperature().get::<degree_celsius>(), 32.88, 1e-3);
+
+    // Superheated steam from the verification table of region 2.
+    let state = by_pressure_entropy(
+        Pressure::new::<megapascal>(30.0),
+        SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(5.17540298),
+    )
+    .unwrap();
+    assert_eq!(state.region(), Region::Region2);
+    assert_close(state.temperature().get::<kelvin>(), 700.0, 1e-6);
+}
+
+#[test]
+fn temperature_volume() {
+    let saturated = by_pressure_enthalpy(
+        Pressure::new::<bar>(70.0),
+        AvailableEnergy::new::<kilojoule_per_kilogram>(2000.0),
+    )
+    .unwrap();
+    let state =
+        by_temperature_volume(saturated.temperature(), saturated.specific_volume()).unwrap();
+    assert_eq!(state.region(), Region::Region4);
+    assert_close(
+        state.quality().unwrap().get::<ratio>(),
+        saturated.quality().unwrap().get::<ratio>(),
+        1e-9,
+    );
+    assert_close(state.pressure().get::<bar>(), 70.0, 1e-6);
+
+    let supercritical = by_pressure_enthalpy(
+        Pressure::new::<megapascal>(25.0),
+        AvailableEnergy::new::<kilojoule_per_kilogram>(2000.0),
+    )
+    .unwrap();
+    let state = by_temperature_volume(supercritical.temperature(), supercritical.specific_volume())
+        .unwrap();
+    assert!(state.quality().is_none());
+    assert_close(state.pressure().get::<megapascal>(), 25.0, 1e-6);
+}
+
+#[test]
+fn internal_energy_volume() {
+    for (pressure, specific_enthalpy) in [
+        (1.0, 100.0),
+        (70.0, 2000.0),
+        (20.0, 3200.0),
+        (250.0, 2000.0),
+    ] {
+        let expected = by_pressure_enthalpy(
+            Pressure::new::<bar>(pressure),
+            AvailableEnergy::new::<kilojoule_per_kilogram>(specific_enthalpy),
+        )
+        .unwrap();
+        let state = by_internal_energy_volume(
+            expected.specific_internal_energy(),
+            expected.specific_volume(),
+        )
+        .unwrap();
+        assert_close(
+            state.temperature().get::<kelvin>(),
+            expected.temperature().get::<kelvin>(),
+            1e-6,
+        );
+        assert_close(state.pressure().get::<bar>(), pressure, 1e-4);
+        assert_close(
+            state.specific_enthalpy().get::<kilojoule_per_kilogram>(),
+            specific_enthalpy,
+            1e-6,
+        );
+    }
+
+    // Colder than the lowest temperature of IAPWS-IF97.
+    assert!(by_internal_energy_volume(
+        AvailableEnergy::new::<kilojoule_per_kilogram>(-100.0),
+        by_pressure_enthalpy(
+            Pressure::new::<bar>(1.0),
+            AvailableEnergy::new::<kilojoule_per_kilogram>(100.0),
+        )
+        .unwrap()
+        .specific_volume(),
+    )
+    .is_err());
+}
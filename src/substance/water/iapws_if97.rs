@@ -0,0 +1,1450 @@
+//! The IAPWS Industrial Formulation 1997 for the thermodynamic properties of water and steam (IAPWS-IF97).
+//!
+//! Internally, all equations are evaluated in the units of the release (MPa, K, kJ/kg, kJ/(kg K)).
+//! The public interface uses `uom` quantities.
+
+use uom::si::{
+    available_energy::kilojoule_per_kilogram,
+    f64::{
+        AvailableEnergy, MassDensity, Pressure, SpecificHeatCapacity, ThermodynamicTemperature,
+        Velocity,
+    },
+    mass_density::kilogram_per_cubic_meter,
+    pressure::megapascal,
+    specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+    thermodynamic_temperature::kelvin,
+    velocity::meter_per_second,
+};
+
+use crate::error::{Error, Result};
+
+#[cfg(test)]
+mod tests;
+
+/// Specific gas constant of water in kJ/(kg K).
+const R: f64 = 0.461526;
+/// Critical temperature in K.
+const CRITICAL_TEMPERATURE: f64 = 647.096;
+/// Critical pressure in MPa.
+const CRITICAL_PRESSURE: f64 = 22.064;
+/// Critical density in kg/m^3.
+const CRITICAL_DENSITY: f64 = 322.0;
+
+/// Lowest temperature covered by the formulation in K.
+const MINIMUM_TEMPERATURE: f64 = 273.15;
+/// Highest temperature of regions 1 and 3 in K.
+const REGION_1_MAXIMUM_TEMPERATURE: f64 = 623.15;
+/// Highest temperature of region 3 in K.
+const REGION_3_MAXIMUM_TEMPERATURE: f64 = 863.15;
+/// Highest temperature of region 2 in K.
+const REGION_2_MAXIMUM_TEMPERATURE: f64 = 1073.15;
+/// Highest temperature of region 5 in K.
+const MAXIMUM_TEMPERATURE: f64 = 2273.15;
+/// Highest pressure of regions 1, 2 and 3 in MPa.
+const MAXIMUM_PRESSURE: f64 = 100.0;
+/// Lowest pressure searched when inverting density in MPa.
+const MINIMUM_PRESSURE: f64 = 1e-12;
+/// Highest pressure of region 5 in MPa.
+const REGION_5_MAXIMUM_PRESSURE: f64 = 50.0;
+/// Upper bound for densities searched in region 3 in kg/m^3.
+const REGION_3_MAXIMUM_DENSITY: f64 = 800.0;
+
+/// The regions of IAPWS-IF97.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Region {
+    /// Compressed liquid up to 623.15 K.
+    Region1,
+    /// Superheated steam up to 1073.15 K.
+    Region2,
+    /// The region around the critical point, above 623.15 K and above the B23 boundary.
+    Region3,
+    /// The saturation line.
+    Region4,
+    /// High-temperature steam above 1073.15 K.
+    Region5,
+}
+
+/// The thermodynamic state of water at a single point, as computed by IAPWS-IF97.
+#[derive(Debug, Clone, Copy)]
+pub struct Properties {
+    region: Region,
+    pressure: Pressure,
+    temperature: ThermodynamicTemperature,
+    density: MassDensity,
+    specific_enthalpy: AvailableEnergy,
+    specific_internal_energy: AvailableEnergy,
+    specific_entropy: SpecificHeatCapacity,
+    specific_isobaric_heat_capacity: SpecificHeatCapacity,
+    speed_of_sound: Velocity,
+}
+
+impl Properties {
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    pub fn density(&self) -> MassDensity {
+        self.density
+    }
+
+    pub fn specific_enthalpy(&self) -> AvailableEnergy {
+        self.specific_enthalpy
+    }
+
+    pub fn specific_internal_energy(&self) -> AvailableEnergy {
+        self.specific_internal_energy
+    }
+
+    pub fn specific_entropy(&self) -> SpecificHeatCapacity {
+        self.specific_entropy
+    }
+
+    pub fn specific_isobaric_heat_capacity(&self) -> SpecificHeatCapacity {
+        self.specific_isobaric_heat_capacity
+    }
+
+    pub fn speed_of_sound(&self) -> Velocity {
+        self.speed_of_sound
+    }
+}
+
+/// The properties in the units of the release.
+#[derive(Debug, Clone, Copy)]
+struct RawProperties {
+    region: Region,
+    /// MPa
+    pressure: f64,
+    /// K
+    temperature: f64,
+    /// kg/m^3
+    density: f64,
+    /// kJ/kg
+    specific_enthalpy: f64,
+    /// kJ/kg
+    specific_internal_energy: f64,
+    /// kJ/(kg K)
+    specific_entropy: f64,
+    /// kJ/(kg K)
+    specific_isobaric_heat_capacity: f64,
+    /// m/s
+    speed_of_sound: f64,
+}
+
+impl From<RawProperties> for Properties {
+    fn from(raw: RawProperties) -> Self {
+        Self {
+            region: raw.region,
+            pressure: Pressure::new::<megapascal>(raw.pressure),
+            temperature: ThermodynamicTemperature::new::<kelvin>(raw.temperature),
+            density: MassDensity::new::<kilogram_per_cubic_meter>(raw.density),
+            specific_enthalpy: AvailableEnergy::new::<kilojoule_per_kilogram>(
+                raw.specific_enthalpy,
+            ),
+            specific_internal_energy: AvailableEnergy::new::<kilojoule_per_kilogram>(
+                raw.specific_internal_energy,
+            ),
+            specific_entropy: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(
+                raw.specific_entropy,
+            ),
+            specific_isobaric_heat_capacity: SpecificHeatCapacity::new::<
+                kilojoule_per_kilogram_kelvin,
+            >(raw.specific_isobaric_heat_capacity),
+            speed_of_sound: Velocity::new::<meter_per_second>(raw.speed_of_sound),
+        }
+    }
+}
+
+pub fn critical_temperature() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(CRITICAL_TEMPERATURE)
+}
+
+pub fn critical_pressure() -> Pressure {
+    Pressure::new::<megapascal>(CRITICAL_PRESSURE)
+}
+
+pub fn critical_density() -> MassDensity {
+    MassDensity::new::<kilogram_per_cubic_meter>(CRITICAL_DENSITY)
+}
+
+/// The region of the formulation that contains the given state.
+pub fn region(pressure: Pressure, temperature: ThermodynamicTemperature) -> Result<Region> {
+    raw_region(pressure.get::<megapascal>(), temperature.get::<kelvin>())
+}
+
+/// The properties of water at the given pressure and temperature.
+/// Exactly on the saturation line below 623.15 K, this returns the liquid state.
+pub fn properties(pressure: Pressure, temperature: ThermodynamicTemperature) -> Result<Properties> {
+    raw_properties(pressure.get::<megapascal>(), temperature.get::<kelvin>()).map(Into::into)
+}
+
+/// The properties of water at the given density and temperature.
+/// Returns an error if the state lies inside the two-phase region.
+pub fn properties_by_density_temperature(
+    density: MassDensity,
+    temperature: ThermodynamicTemperature,
+) -> Result<Properties> {
+    raw_properties_by_density_temperature(
+        density.get::<kilogram_per_cubic_meter>(),
+        temperature.get::<kelvin>(),
+    )
+    .map(Into::into)
+}
+
+/// The saturation pressure at the given temperature (region 4).
+pub fn saturation_pressure(temperature: ThermodynamicTemperature) -> Result<Pressure> {
+    let temperature = temperature.get::<kelvin>();
+    if !(MINIMUM_TEMPERATURE..=CRITICAL_TEMPERATURE).contains(&temperature) {
+        return Err(out_of_range_temperature(temperature));
+    }
+    Ok(Pressure::new::<megapascal>(region4_saturation_pressure(
+        temperature,
+    )))
+}
+
+/// The saturation temperature at the given pressure (region 4).
+pub fn saturation_temperature(pressure: Pressure) -> Result<ThermodynamicTemperature> {
+    let pressure = pressure.get::<megapascal>();
+    if !(MINIMUM_SATURATION_PRESSURE..=CRITICAL_PRESSURE).contains(&pressure) {
+        return Err(out_of_range_pressure(pressure));
+    }
+    Ok(ThermodynamicTemperature::new::<kelvin>(
+        region4_saturation_temperature(pressure),
+    ))
+}
+
+/// The properties of saturated liquid at the given temperature.
+pub fn saturated_liquid(temperature: ThermodynamicTemperature) -> Result<Properties> {
+    raw_saturated_liquid(temperature.get::<kelvin>()).map(Into::into)
+}
+
+/// The properties of saturated vapour at the given temperature.
+pub fn saturated_vapour(temperature: ThermodynamicTemperature) -> Result<Properties> {
+    raw_saturated_vapour(temperature.get::<kelvin>()).map(Into::into)
+}
+
+/// The temperature of water with the given pressure and specific enthalpy.
+/// Inside the two-phase region, this is the saturation temperature.
+///
+/// Uses the backward equations of regions 1 and 2 as initial estimate where available,
+/// and refines it to be consistent with the basic equations.
+pub fn temperature_by_pressure_enthalpy(
+    pressure: Pressure,
+    specific_enthalpy: AvailableEnergy,
+) -> Result<ThermodynamicTemperature> {
+    raw_temperature_by_pressure_property(
+        pressure.get::<megapascal>(),
+        specific_enthalpy.get::<kilojoule_per_kilogram>(),
+        StateProperty::Enthalpy,
+    )
+    .map(ThermodynamicTemperature::new::<kelvin>)
+}
+
+/// The temperature of water with the given pressure and specific entropy.
+/// Inside the two-phase region, this is the saturation temperature.
+///
+/// Uses the backward equation of region 1 as initial estimate,
+/// and refines it to be consistent with the basic equations.
+pub fn temperature_by_pressure_entropy(
+    pressure: Pressure,
+    specific_entropy: SpecificHeatCapacity,
+) -> Result<ThermodynamicTemperature> {
+    raw_temperature_by_pressure_property(
+        pressure.get::<megapascal>(),
+        specific_entropy.get::<kilojoule_per_kilogram_kelvin>(),
+        StateProperty::Entropy,
+    )
+    .map(ThermodynamicTemperature::new::<kelvin>)
+}
+
+fn out_of_range(pressure: f64, temperature: f64) -> Error {
+    Error::OutsideIapwsIf97Range {
+        state: format!("p = {pressure} MPa, T = {temperature} K"),
+    }
+}
+
+fn out_of_range_temperature(temperature: f64) -> Error {
+    Error::OutsideIapwsIf97Range {
+        state: format!("T = {temperature} K"),
+    }
+}
+
+fn out_of_range_pressure(pressure: f64) -> Error {
+    Error::OutsideIapwsIf97Range {
+        state: format!("p = {pressure} MPa"),
+    }
+}
+
+fn raw_region(pressure: f64, temperature: f64) -> Result<Region> {
+    if !(f64::MIN_POSITIVE..=MAXIMUM_PRESSURE).contains(&pressure)
+        || !(MINIMUM_TEMPERATURE..=MAXIMUM_TEMPERATURE).contains(&temperature)
+    {
+        return Err(out_of_range(pressure, temperature));
+    }
+
+    if temperature <= REGION_1_MAXIMUM_TEMPERATURE {
+        if pressure >= region4_saturation_pressure(temperature) {
+            Ok(Region::Region1)
+        } else {
+            Ok(Region::Region2)
+        }
+    } else if temperature <= REGION_3_MAXIMUM_TEMPERATURE {
+        if pressure > b23_pressure(temperature) {
+            Ok(Region::Region3)
+        } else {
+            Ok(Region::Region2)
+        }
+    } else if temperature <= REGION_2_MAXIMUM_TEMPERATURE {
+        Ok(Region::Region2)
+    } else if pressure <= REGION_5_MAXIMUM_PRESSURE {
+        Ok(Region::Region5)
+    } else {
+        Err(out_of_range(pressure, temperature))
+    }
+}
+
+fn raw_properties(pressure: f64, temperature: f64) -> Result<RawProperties> {
+    Ok(match raw_region(pressure, temperature)? {
+        Region::Region1 => region1(pressure, temperature),
+        Region::Region2 => region2(pressure, temperature),
+        Region::Region3 => {
+            let density = region3_density(pressure, temperature)?;
+            region3(density, temperature)
+        }
+        Region::Region4 => {
+            unreachable!("a state given by pressure and temperature is never in region 4")
+        }
+        Region::Region5 => region5(pressure, temperature),
+    })
+}
+
+fn raw_properties_by_density_temperature(density: f64, temperature: f64) -> Result<RawProperties> {
+    if !(f64::MIN_POSITIVE..=f64::INFINITY).contains(&density)
+        || !(MINIMUM_TEMPERATURE..=MAXIMUM_TEMPERATURE).contains(&temperature)
+    {
+        return Err(Error::OutsideIapwsIf97Range {
+            state: format!("rho = {density} kg/m^3, T = {temperature} K"),
+        });
+    }
+
+    let two_phase = || Error::OutsideIapwsIf97Range {
+        state: format!("rho = {density} kg/m^3, T = {temperature} K is in the two-phase region"),
+    };
+
+    if temperature <= REGION_1_MAXIMUM_TEMPERATURE {
+        let saturation_pressure = region4_saturation_pressure(temperature);
+        if density <= region2(saturation_pressure, temperature).density {
+            let pressure = pressure_by_density(
+                region2_gibbs,
+                density,
+                temperature,
+                MINIMUM_PRESSURE,
+                saturation_pressure,
+            )?;
+            Ok(region2(pressure, temperature))
+        } else if density >= region1(saturation_pressure, temperature).density {
+            let pressure = pressure_by_density(
+                region1_gibbs,
+                density,
+                temperature,
+                saturation_pressure,
+                MAXIMUM_PRESSURE,
+            )?;
+            Ok(region1(pressure, temperature))
+        } else {
+            Err(two_phase())
+        }
+    } else if temperature <= REGION_3_MAXIMUM_TEMPERATURE {
+        let boundary_pressure = b23_pressure(temperature);
+        if density <= region2(boundary_pressure, temperature).density {
+            let pressure = pressure_by_density(
+                region2_gibbs,
+                density,
+                temperature,
+                MINIMUM_PRESSURE,
+                boundary_pressure,
+            )?;
+            Ok(region2(pressure, temperature))
+        } else {
+            if temperature < CRITICAL_TEMPERATURE
+                && density > raw_saturated_vapour(temperature)?.density
+                && density < raw_saturated_liquid(temperature)?.density
+            {
+                return Err(two_phase());
+            }
+            let properties = region3(density, temperature);
+            if properties.pressure > MAXIMUM_PRESSURE {
+                return Err(out_of_range(properties.pressure, temperature));
+            }
+            Ok(properties)
+        }
+    } else if temperature <= REGION_2_MAXIMUM_TEMPERATURE {
+        let pressure = pressure_by_density(
+            region2_gibbs,
+            density,
+            temperature,
+            MINIMUM_PRESSURE,
+            MAXIMUM_PRESSURE,
+        )?;
+        Ok(region2(pressure, temperature))
+    } else {
+        let pressure = pressure_by_density(
+            region5_gibbs,
+            density,
+            temperature,
+            MINIMUM_PRESSURE,
+            REGION_5_MAXIMUM_PRESSURE,
+        )?;
+        Ok(region5(pressure, temperature))
+    }
+}
+
+/// Solve for the pressure at which a region given by a Gibbs free energy has the given density.
+fn pressure_by_density(
+    gibbs: fn(f64, f64) -> Gibbs,
+    density: f64,
+    temperature: f64,
+    minimum_pressure: f64,
+    maximum_pressure: f64,
+) -> Result<f64> {
+    find_root(
+        |pressure| {
+            let (value, derivative) = gibbs_density_derivative(gibbs, pressure, temperature);
+            (value - density, derivative)
+        },
+        minimum_pressure,
+        maximum_pressure,
+    )
+    .ok_or_else(|| Error::OutsideIapwsIf97Range {
+        state: format!("rho = {density} kg/m^3, T = {temperature} K"),
+    })
+}
+
+fn raw_saturated_liquid(temperature: f64) -> Result<RawProperties> {
+    if !(MINIMUM_TEMPERATURE..=CRITICAL_TEMPERATURE).contains(&temperature) {
+        return Err(out_of_range_temperature(temperature));
+    }
+
+    let pressure = region4_saturation_pressure(temperature);
+    if temperature <= REGION_1_MAXIMUM_TEMPERATURE {
+        Ok(region1(pressure, temperature))
+    } else {
+        let density = region3_saturated_density(temperature, true)?;
+        Ok(region3(density, temperature))
+    }
+}
+
+fn raw_saturated_vapour(temperature: f64) -> Result<RawProperties> {
+    if !(MINIMUM_TEMPERATURE..=CRITICAL_TEMPERATURE).contains(&temperature) {
+        return Err(out_of_range_temperature(temperature));
+    }
+
+    let pressure = region4_saturation_pressure(temperature);
+    if temperature <= REGION_1_MAXIMUM_TEMPERATURE {
+        Ok(region2(pressure, temperature))
+    } else {
+        let density = region3_saturated_density(temperature, false)?;
+        Ok(region3(density, temperature))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StateProperty {
+    Enthalpy,
+    Entropy,
+}
+
+impl StateProperty {
+    /// The value of this property, and its derivative with respect to temperature at constant pressure.
+    fn of(self, properties: &RawProperties) -> (f64, f64) {
+        match self {
+            StateProperty::Enthalpy => (
+                properties.specific_enthalpy,
+                properties.specific_isobaric_heat_capacity,
+            ),
+            StateProperty::Entropy => (
+                properties.specific_entropy,
+                properties.specific_isobaric_heat_capacity / properties.temperature,
+            ),
+        }
+    }
+}
+
+fn raw_temperature_by_pressure_property(
+    pressure: f64,
+    value: f64,
+    property: StateProperty,
+) -> Result<f64> {
+    let out_of_range = || Error::OutsideIapwsIf97Range {
+        state: match property {
+            StateProperty::Enthalpy => format!("p = {pressure} MPa, h = {value} kJ/kg"),
+            StateProperty::Entropy => format!("p = {pressure} MPa, s = {value} kJ/(kg K)"),
+        },
+    };
+    if !(MINIMUM_SATURATION_PRESSURE..=MAXIMUM_PRESSURE).contains(&pressure) {
+        return Err(out_of_range());
+    }
+
+    // Solves for the temperature in a region given by pressure and temperature.
+    let solve = |region: fn(f64, f64) -> RawProperties, low: f64, high: f64| {
+        find_root(
+            |temperature| {
+                let (property_value, derivative) = property.of(&region(pressure, temperature));
+                (property_value - value, derivative)
+            },
+            low,
+            high,
+        )
+        .ok_or_else(out_of_range)
+    };
+    let solve_region3 = |low: f64, high: f64| {
+        find_root(
+            |temperature| match region3_density(pressure, temperature) {
+                Ok(density) => {
+                    let (property_value, derivative) = property.of(&region3(density, temperature));
+                    (property_value - value, derivative)
+                }
+                Err(_) => (f64::NAN, f64::NAN),
+            },
+            low,
+            high,
+        )
+        .ok_or_else(out_of_range)
+    };
+
+    let minimum = property.of(&region1(pressure, MINIMUM_TEMPERATURE)).0;
+    if value < minimum {
+        return Err(out_of_range());
+    }
+
+    if pressure <= region4_saturation_pressure(REGION_1_MAXIMUM_TEMPERATURE) {
+        let saturation_temperature = region4_saturation_temperature(pressure);
+        let liquid = property.of(&region1(pressure, saturation_temperature)).0;
+        let vapour = property.of(&region2(pressure, saturation_temperature)).0;
+
+        if value <= liquid {
+            let estimate = match property {
+                StateProperty::Enthalpy => {
+                    region1_temperature_by_pressure_enthalpy(pressure, value)
+                }
+                StateProperty::Entropy => region1_temperature_by_pressure_entropy(pressure, value),
+            };
+            refine(
+                estimate,
+                MINIMUM_TEMPERATURE,
+                saturation_temperature,
+                |temperature| property.of(&region1(pressure, temperature)),
+                value,
+            )
+            .or_else(|| solve(region1, MINIMUM_TEMPERATURE, saturation_temperature).ok())
+            .ok_or_else(out_of_range)
+        } else if value < vapour {
+            Ok(saturation_temperature)
+        } else {
+            solve_steam(pressure, value, property, saturation_temperature).ok_or_else(out_of_range)
+        }
+    } else {
+        let region1_maximum = property
+            .of(&region1(pressure, REGION_1_MAXIMUM_TEMPERATURE))
+            .0;
+        let boundary_temperature = b23_temperature(pressure);
+        let region2_minimum = property.of(&region2(pressure, boundary_temperature)).0;
+
+        if value <= region1_maximum {
+            let estimate = match property {
+                StateProperty::Enthalpy => {
+                    region1_temperature_by_pressure_enthalpy(pressure, value)
+                }
+                StateProperty::Entropy => region1_temperature_by_pressure_entropy(pressure, value),
+            };
+            refine(
+                estimate,
+                MINIMUM_TEMPERATURE,
+                REGION_1_MAXIMUM_TEMPERATURE,
+                |temperature| property.of(&region1(pressure, temperature)),
+                value,
+            )
+            .or_else(|| solve(region1, MINIMUM_TEMPERATURE, REGION_1_MAXIMUM_TEMPERATURE).ok())
+            .ok_or_else(out_of_range)
+        } else if value >= region2_minimum {
+            solve_steam(pressure, value, property, boundary_temperature).ok_or_else(out_of_range)
+        } else if pressure < CRITICAL_PRESSURE {
+            let saturation_temperature = region4_saturation_temperature(pressure);
+            let liquid = property
+                .of(&region3(
+                    region3_saturated_density(saturation_temperature, true)?,
+                    saturation_temperature,
+                ))
+                .0;
+            let vapour = property
+                .of(&region3(
+                    region3_saturated_density(saturation_temperature, false)?,
+                    saturation_temperature,
+                ))
+                .0;
+            if value <= liquid {
+                solve_region3(REGION_1_MAXIMUM_TEMPERATURE, saturation_temperature)
+            } else if value < vapour {
+                Ok(saturation_temperature)
+            } else {
+                solve_region3(saturation_temperature, boundary_temperature)
+            }
+        } else {
+            solve_region3(REGION_1_MAXIMUM_TEMPERATURE, boundary_temperature)
+        }
+    }
+}
+
+/// Solve for the temperature of steam (regions 2 and 5) above the given minimum temperature.
+fn solve_steam(
+    pressure: f64,
+    value: f64,
+    property: StateProperty,
+    minimum_temperature: f64,
+) -> Option<f64> {
+    let region2_maximum = property
+        .of(&region2(pressure, REGION_2_MAXIMUM_TEMPERATURE))
+        .0;
+    if value <= region2_maximum {
+        let estimate = match property {
+            StateProperty::Enthalpy => region2_temperature_by_pressure_enthalpy(pressure, value),
+            StateProperty::Entropy => None,
+        }
+        // Without a backward equation, we start in the middle of the region.
+        .unwrap_or((minimum_temperature + REGION_2_MAXIMUM_TEMPERATURE) / 2.0);
+        refine(
+            estimate,
+            minimum_temperature,
+            REGION_2_MAXIMUM_TEMPERATURE,
+            |temperature| property.of(&region2(pressure, temperature)),
+            value,
+        )
+    } else if pressure <= REGION_5_MAXIMUM_PRESSURE {
+        find_root(
+            |temperature| {
+                let (property_value, derivative) = property.of(&region5(pressure, temperature));
+                (property_value - value, derivative)
+            },
+            REGION_2_MAXIMUM_TEMPERATURE,
+            MAXIMUM_TEMPERATURE,
+        )
+    } else {
+        None
+    }
+}
+
+/// Refine an estimate of a temperature using Newton's method within the given bounds.
+fn refine(
+    estimate: f64,
+    low: f64,
+    high: f64,
+    property: impl Fn(f64) -> (f64, f64),
+    value: f64,
+) -> Option<f64> {
+    let estimate = estimate.clamp(low, high);
+    find_root_from(
+        |temperature| {
+            let (property_value, derivative) = property(temperature);
+            (property_value - value, derivative)
+        },
+        low,
+        high,
+        estimate,
+    )
+}
+
+/// Find the root of a monotonic function within the given bracket.
+/// The function returns its value and derivative.
+/// Newton steps are taken when they stay inside the bracket, otherwise the bracket is bisected.
+fn find_root(function: impl Fn(f64) -> (f64, f64), low: f64, high: f64) -> Option<f64> {
+    find_root_from(function, low, high, (low + high) / 2.0)
+}
+
+fn find_root_from(
+    function: impl Fn(f64) -> (f64, f64),
+    mut low: f64,
+    mut high: f64,
+    initial: f64,
+) -> Option<f64> {
+    let low_value = function(low).0;
+    let high_value = function(high).0;
+    if low_value == 0.0 {
+        return Some(low);
+    }
+    if high_value == 0.0 {
+        return Some(high);
+    }
+    if low_value.signum() == high_value.signum() || low_value.is_nan() || high_value.is_nan() {
+        return None;
+    }
+    let increasing = high_value > low_value;
+
+    let mut x = initial;
+    for _ in 0..200 {
+        let (value, derivative) = function(x);
+        if value == 0.0 {
+            return Some(x);
+        }
+        if value.is_nan() {
+            return None;
+        }
+        if (value > 0.0) == increasing {
+            high = x;
+        } else {
+            low = x;
+        }
+
+        let newton = x - value / derivative;
+        let next = if newton.is_finite() && newton > low && newton < high {
+            newton
+        } else {
+            (low + high) / 2.0
+        };
+
+        if (next - x).abs() <= 1e-13 * x.abs().max(1e-10) || high - low <= 1e-14 * x.abs() {
+            return Some(next);
+        }
+        x = next;
+    }
+
+    None
+}
+
+/// The derivative of density with respect to pressure at constant temperature of a region given by a Gibbs free energy.
+fn gibbs_density_derivative(
+    gibbs: fn(f64, f64) -> Gibbs,
+    pressure: f64,
+    temperature: f64,
+) -> (f64, f64) {
+    let g = gibbs(pressure, temperature);
+    // v = R T pi gamma_pi / p, in m^3/kg
+    let specific_volume = R * temperature * g.pi * g.gamma_pi / pressure * 1e-3;
+    // dv/dp = R T pi^2 gamma_pipi / p^2
+    let specific_volume_derivative =
+        R * temperature * g.pi * g.pi * g.gamma_pipi / (pressure * pressure) * 1e-3;
+    let density = 1.0 / specific_volume;
+    (density, -density * density * specific_volume_derivative)
+}
+
+/// A dimensionless Gibbs free energy and its derivatives.
+#[derive(Debug, Clone, Copy, Default)]
+struct Gibbs {
+    pi: f64,
+    tau: f64,
+    gamma: f64,
+    gamma_pi: f64,
+    gamma_pipi: f64,
+    gamma_tau: f64,
+    gamma_tautau: f64,
+    gamma_pitau: f64,
+}
+
+impl Gibbs {
+    fn properties(&self, region: Region, pressure: f64, temperature: f64) -> RawProperties {
+        let Gibbs {
+            pi,
+            tau,
+            gamma,
+            gamma_pi,
+            gamma_pipi,
+            gamma_tau,
+            gamma_tautau,
+            gamma_pitau,
+        } = *self;
+        let specific_volume = R * temperature * pi * gamma_pi / pressure * 1e-3;
+        let speed_of_sound_squared = R * 1e3 * temperature * gamma_pi * gamma_pi
+            / ((gamma_pi - tau * gamma_pitau).powi(2) / (tau * tau * gamma_tautau) - gamma_pipi);
+
+        RawProperties {
+            region,
+            pressure,
+            temperature,
+            density: 1.0 / specific_volume,
+            specific_enthalpy: R * temperature * tau * gamma_tau,
+            specific_internal_energy: R * temperature * (tau * gamma_tau - pi * gamma_pi),
+            specific_entropy: R * (tau * gamma_tau - gamma),
+            specific_isobaric_heat_capacity: -R * tau * tau * gamma_tautau,
+            speed_of_sound: speed_of_sound_squared.sqrt(),
+        }
+    }
+}
+
+fn region1(pressure: f64, temperature: f64) -> RawProperties {
+    region1_gibbs(pressure, temperature).properties(Region::Region1, pressure, temperature)
+}
+
+fn region1_gibbs(pressure: f64, temperature: f64) -> Gibbs {
+    let pi = pressure / 16.53;
+    let tau = 1386.0 / temperature;
+    let a = 7.1 - pi;
+    let b = tau - 1.222;
+
+    let mut g = Gibbs {
+        pi,
+        tau,
+        ..Default::default()
+    };
+    for &(i, j, n) in constants::REGION_1 {
+        let fi = f64::from(i);
+        let fj = f64::from(j);
+        g.gamma += n * a.powi(i) * b.powi(j);
+        g.gamma_pi -= n * fi * a.powi(i - 1) * b.powi(j);
+        g.gamma_pipi += n * fi * (fi - 1.0) * a.powi(i - 2) * b.powi(j);
+        g.gamma_tau += n * a.powi(i) * fj * b.powi(j - 1);
+        g.gamma_tautau += n * a.powi(i) * fj * (fj - 1.0) * b.powi(j - 2);
+        g.gamma_pitau -= n * fi * a.powi(i - 1) * fj * b.powi(j - 1);
+    }
+    g
+}
+
+fn region2(pressure: f64, temperature: f64) -> RawProperties {
+    region2_gibbs(pressure, temperature).properties(Region::Region2, pressure, temperature)
+}
+
+fn region2_gibbs(pressure: f64, temperature: f64) -> Gibbs {
+    steam_gibbs(
+        pressure,
+        540.0 / temperature,
+        0.5,
+        constants::REGION_2_IDEAL,
+        constants::REGION_2_RESIDUAL,
+    )
+}
+
+fn region5(pressure: f64, temperature: f64) -> RawProperties {
+    region5_gibbs(pressure, temperature).properties(Region::Region5, pressure, temperature)
+}
+
+fn region5_gibbs(pressure: f64, temperature: f64) -> Gibbs {
+    steam_gibbs(
+        pressure,
+        1000.0 / temperature,
+        0.0,
+        constants::REGION_5_IDEAL,
+        constants::REGION_5_RESIDUAL,
+    )
+}
+
+/// The Gibbs free energy of regions 2 and 5, which consists of an ideal-gas part and a residual part.
+fn steam_gibbs(
+    pressure: f64,
+    tau: f64,
+    tau_offset: f64,
+    ideal: &[(i32, f64)],
+    residual: &[(i32, i32, f64)],
+) -> Gibbs {
+    let pi = pressure;
+    let mut g = Gibbs {
+        pi,
+        tau,
+        gamma: pi.ln(),
+        gamma_pi: 1.0 / pi,
+        gamma_pipi: -1.0 / (pi * pi),
+        ..Default::default()
+    };
+
+    for &(j, n) in ideal {
+        let fj = f64::from(j);
+        g.gamma += n * tau.powi(j);
+        g.gamma_tau += n * fj * tau.powi(j - 1);
+        g.gamma_tautau += n * fj * (fj - 1.0) * tau.powi(j - 2);
+    }
+
+    let b = tau - tau_offset;
+    for &(i, j, n) in residual {
+        let fi = f64::from(i);
+        let fj = f64::from(j);
+        g.gamma += n * pi.powi(i) * b.powi(j);
+        g.gamma_pi += n * fi * pi.powi(i - 1) * b.powi(j);
+        g.gamma_pipi += n * fi * (fi - 1.0) * pi.powi(i - 2) * b.powi(j);
+        g.gamma_tau += n * pi.powi(i) * fj * b.powi(j - 1);
+        g.gamma_tautau += n * pi.powi(i) * fj * (fj - 1.0) * b.powi(j - 2);
+        g.gamma_pitau += n * fi * pi.powi(i - 1) * fj * b.powi(j - 1);
+    }
+    g
+}
+
+/// A dimensionless Helmholtz free energy and its derivatives.
+#[derive(Debug, Clone, Copy, Default)]
+struct Helmholtz {
+    delta: f64,
+    tau: f64,
+    phi: f64,
+    phi_delta: f64,
+    phi_deltadelta: f64,
+    phi_tau: f64,
+    phi_tautau: f64,
+    phi_deltatau: f64,
+}
+
+fn region3_helmholtz(density: f64, temperature: f64) -> Helmholtz {
+    let delta = density / CRITICAL_DENSITY;
+    let tau = CRITICAL_TEMPERATURE / temperature;
+    let n1 = constants::REGION_3_LOGARITHMIC;
+
+    let mut f = Helmholtz {
+        delta,
+        tau,
+        phi: n1 * delta.ln(),
+        phi_delta: n1 / delta,
+        phi_deltadelta: -n1 / (delta * delta),
+        ..Default::default()
+    };
+    for &(i, j, n) in constants::REGION_3 {
+        let fi = f64::from(i);
+        let fj = f64::from(j);
+        f.phi += n * delta.powi(i) * tau.powi(j);
+        f.phi_delta += n * fi * delta.powi(i - 1) * tau.powi(j);
+        f.phi_deltadelta += n * fi * (fi - 1.0) * delta.powi(i - 2) * tau.powi(j);
+        f.phi_tau += n * delta.powi(i) * fj * tau.powi(j - 1);
+        f.phi_tautau += n * delta.powi(i) * fj * (fj - 1.0) * tau.powi(j - 2);
+        f.phi_deltatau += n * fi * delta.powi(i - 1) * fj * tau.powi(j - 1);
+    }
+    f
+}
+
+fn region3(density: f64, temperature: f64) -> RawProperties {
+    let Helmholtz {
+        delta,
+        tau,
+        phi,
+        phi_delta,
+        phi_deltadelta,
+        phi_tau,
+        phi_tautau,
+        phi_deltatau,
+    } = region3_helmholtz(density, temperature);
+    let rt = R * temperature;
+    let compressibility_term = 2.0 * delta * phi_delta + delta * delta * phi_deltadelta;
+    let cross_term = (delta * phi_delta - delta * tau * phi_deltatau).powi(2);
+
+    RawProperties {
+        region: Region::Region3,
+        pressure: density * rt * delta * phi_delta * 1e-3,
+        temperature,
+        density,
+        specific_enthalpy: rt * (tau * phi_tau + delta * phi_delta),
+        specific_internal_energy: rt * tau * phi_tau,
+        specific_entropy: R * (tau * phi_tau - phi),
+        specific_isobaric_heat_capacity: R
+            * (-tau * tau * phi_tautau + cross_term / compressibility_term),
+        speed_of_sound: (rt * 1e3 * (compressibility_term - cross_term / (tau * tau * phi_tautau)))
+            .sqrt(),
+    }
+}
+
+/// The pressure of region 3 and its derivative with respect to density.
+fn region3_pressure(density: f64, temperature: f64) -> (f64, f64) {
+    let f = region3_helmholtz(density, temperature);
+    let rt = R * temperature;
+    (
+        density * rt * f.delta * f.phi_delta * 1e-3,
+        rt * (2.0 * f.delta * f.phi_delta + f.delta * f.delta * f.phi_deltadelta) * 1e-3,
+    )
+}
+
+/// The density of region 3 at the given pressure and temperature.
+fn region3_density(pressure: f64, temperature: f64) -> Result<f64> {
+    let not_found = || out_of_range(pressure, temperature);
+    let minimum_density = 0.5 * pressure * 1e3 / (R * temperature);
+
+    let (low, high) = if temperature < CRITICAL_TEMPERATURE {
+        let saturation_pressure = region4_saturation_pressure(temperature);
+        if pressure >= saturation_pressure {
+            (
+                region3_saturated_density(temperature, true)?,
+                REGION_3_MAXIMUM_DENSITY,
+            )
+        } else {
+            (
+                minimum_density,
+                region3_saturated_density(temperature, false)?,
+            )
+        }
+    } else {
+        (minimum_density, REGION_3_MAXIMUM_DENSITY)
+    };
+
+    find_root(
+        |density| {
+            let (value, derivative) = region3_pressure(density, temperature);
+            (value - pressure, derivative)
+        },
+        low,
+        high,
+    )
+    .ok_or_else(not_found)
+}
+
+/// The density of saturated liquid or vapour in region 3.
+/// Uses the auxiliary equations for the saturated densities by Wagner and Pruß as initial estimate.
+fn region3_saturated_density(temperature: f64, liquid: bool) -> Result<f64> {
+    if temperature >= CRITICAL_TEMPERATURE {
+        return Ok(CRITICAL_DENSITY);
+    }
+
+    let theta = 1.0 - temperature / CRITICAL_TEMPERATURE;
+    let mut density = if liquid {
+        let b = [
+            1.99274064,
+            1.09965342,
+            -0.510839303,
+            -1.75493479,
+            -45.5170352,
+            -6.74694450e5,
+        ];
+        let exponents = [
+            1.0 / 3.0,
+            2.0 / 3.0,
+            5.0 / 3.0,
+            16.0 / 3.0,
+            43.0 / 3.0,
+            110.0 / 3.0,
+        ];
+        CRITICAL_DENSITY
+            * (1.0
+                + b.iter()
+                    .zip(exponents)
+                    .map(|(b, exponent)| b * theta.powf(exponent))
+                    .sum::<f64>())
+    } else {
+        let c = [
+            -2.03150240,
+            -2.68302940,
+            -5.38626492,
+            -17.2991605,
+            -44.7586581,
+            -63.9201063,
+        ];
+        let exponents = [
+            2.0 / 6.0,
+            4.0 / 6.0,
+            8.0 / 6.0,
+            18.0 / 6.0,
+            37.0 / 6.0,
+            71.0 / 6.0,
+        ];
+        CRITICAL_DENSITY
+            * c.iter()
+                .zip(exponents)
+                .map(|(c, exponent)| c * theta.powf(exponent))
+                .sum::<f64>()
+                .exp()
+    };
+
+    let saturation_pressure = region4_saturation_pressure(temperature);
+    for _ in 0..100 {
+        let (pressure, derivative) = region3_pressure(density, temperature);
+        let step = (pressure - saturation_pressure) / derivative;
+        if !step.is_finite() || derivative <= 0.0 {
+            break;
+        }
+        density -= step;
+        if step.abs() <= 1e-12 * density {
+            return Ok(density);
+        }
+    }
+
+    Err(out_of_range_temperature(temperature))
+}
+
+/// The lowest pressure on the saturation line, at 273.15 K, in MPa.
+const MINIMUM_SATURATION_PRESSURE: f64 = 611.212677e-6;
+
+fn region4_saturation_pressure(temperature: f64) -> f64 {
+    let n = &constants::REGION_4;
+    let theta = temperature + n[8] / (temperature - n[9]);
+    let a = theta * theta + n[0] * theta + n[1];
+    let b = n[2] * theta * theta + n[3] * theta + n[4];
+    let c = n[5] * theta * theta + n[6] * theta + n[7];
+    (2.0 * c / (-b + (b * b - 4.0 * a * c).sqrt())).powi(4)
+}
+
+fn region4_saturation_temperature(pressure: f64) -> f64 {
+    let n = &constants::REGION_4;
+    let beta = pressure.powf(0.25);
+    let e = beta * beta + n[2] * beta + n[5];
+    let f = n[0] * beta * beta + n[3] * beta + n[6];
+    let g = n[1] * beta * beta + n[4] * beta + n[7];
+    let d = 2.0 * g / (-f - (f * f - 4.0 * e * g).sqrt());
+    (n[9] + d - ((n[9] + d).powi(2) - 4.0 * (n[8] + n[9] * d)).sqrt()) / 2.0
+}
+
+/// The pressure on the boundary between regions 2 and 3.
+fn b23_pressure(temperature: f64) -> f64 {
+    let n = &constants::B23;
+    n[0] + n[1] * temperature + n[2] * temperature * temperature
+}
+
+/// The temperature on the boundary between regions 2 and 3.
+fn b23_temperature(pressure: f64) -> f64 {
+    let n = &constants::B23;
+    n[3] + ((pressure - n[4]) / n[2]).sqrt()
+}
+
+/// The backward equation T(p, h) of region 1.
+fn region1_temperature_by_pressure_enthalpy(pressure: f64, specific_enthalpy: f64) -> f64 {
+    let eta = specific_enthalpy / 2500.0;
+    constants::REGION_1_BACKWARD_PH
+        .iter()
+        .map(|&(i, j, n)| n * pressure.powi(i) * (eta + 1.0).powi(j))
+        .sum()
+}
+
+/// The backward equation T(p, s) of region 1.
+fn region1_temperature_by_pressure_entropy(pressure: f64, specific_entropy: f64) -> f64 {
+    constants::REGION_1_BACKWARD_PS
+        .iter()
+        .map(|&(i, j, n)| n * pressure.powi(i) * (specific_entropy + 2.0).powi(j))
+        .sum()
+}
+
+/// The backward equations T(p, h) of the subregions 2a and 2b.
+/// Subregion 2c, at high pressure close to the saturation line, is not covered and returns `None`.
+fn region2_temperature_by_pressure_enthalpy(pressure: f64, specific_enthalpy: f64) -> Option<f64> {
+    let eta = specific_enthalpy / 2000.0;
+    let (table, pi, eta): (&[(i32, i32, f64)], f64, f64) = if pressure <= 4.0 {
+        (constants::REGION_2A_BACKWARD_PH, pressure, eta - 2.1)
+    } else if specific_enthalpy >= b2bc_enthalpy(pressure) {
+        (constants::REGION_2B_BACKWARD_PH, pressure - 2.0, eta - 2.6)
+    } else {
+        return None;
+    };
+
+    Some(
+        table
+            .iter()
+            .map(|&(i, j, n)| n * pi.powi(i) * eta.powi(j))
+            .sum(),
+    )
+}
+
+/// The enthalpy on the boundary between the subregions 2b and 2c.
+fn b2bc_enthalpy(pressure: f64) -> f64 {
+    let n = &constants::B2BC;
+    n[3] + ((pressure - n[4]) / n[2]).sqrt()
+}
+
+mod constants {
+    //! Coefficients and exponents of the equations, as tuples `(I, J, n)`.
+
+    pub const REGION_1: &[(i32, i32, f64)] = &[
+        (0, -2, 0.14632971213167),
+        (0, -1, -0.84548187169114),
+        (0, 0, -0.37563603672040e1),
+        (0, 1, 0.33855169168385e1),
+        (0, 2, -0.95791963387872),
+        (0, 3, 0.15772038513228),
+        (0, 4, -0.16616417199501e-1),
+        (0, 5, 0.81214629983568e-3),
+        (1, -9, 0.28319080123804e-3),
+        (1, -7, -0.60706301565874e-3),
+        (1, -1, -0.18990068218419e-1),
+        (1, 0, -0.32529748770505e-1),
+        (1, 1, -0.21841717175414e-1),
+        (1, 3, -0.52838357969930e-4),
+        (2, -3, -0.47184321073267e-3),
+        (2, 0, -0.30001780793026e-3),
+        (2, 1, 0.47661393906987e-4),
+        (2, 3, -0.44141845330846e-5),
+        (2, 17, -0.72694996297594e-15),
+        (3, -4, -0.31679644845054e-4),
+        (3, 0, -0.28270797985312e-5),
+        (3, 6, -0.85205128120103e-9),
+        (4, -5, -0.22425281908000e-5),
+        (4, -2, -0.65171222895601e-6),
+        (4, 10, -0.14341729937924e-12),
+        (5, -8, -0.40516996860117e-6),
+        (8, -11, -0.12734301741641e-8),
+        (8, -6, -0.17424871230634e-9),
+        (21, -29, -0.68762131295531e-18),
+        (23, -31, 0.14478307828521e-19),
+        (29, -38, 0.26335781662795e-22),
+        (30, -39, -0.11947622640071e-22),
+        (31, -40, 0.18228094581404e-23),
+        (32, -41, -0.93537087292458e-25),
+    ];
+
+    /// Ideal-gas part of region 2, as tuples `(J, n)`.
+    pub const REGION_2_IDEAL: &[(i32, f64)] = &[
+        (0, -0.96927686500217e1),
+        (1, 0.10086655968018e2),
+        (-5, -0.56087911283020e-2),
+        (-4, 0.71452738081455e-1),
+        (-3, -0.40710498223928),
+        (-2, 0.14240819171444e1),
+        (-1, -0.43839511319450e1),
+        (2, -0.28408632460772),
+        (3, 0.21268463753307e-1),
+    ];
+
+    pub const REGION_2_RESIDUAL: &[(i32, i32, f64)] = &[
+        (1, 0, -0.17731742473213e-2),
+        (1, 1, -0.17834862292358e-1),
+        (1, 2, -0.45996013696365e-1),
+        (1, 3, -0.57581259083432e-1),
+        (1, 6, -0.50325278727930e-1),
+        (2, 1, -0.33032641670203e-4),
+        (2, 2, -0.18948987516315e-3),
+        (2, 4, -0.39392777243355e-2),
+        (2, 7, -0.43797295650573e-1),
+        (2, 36, -0.26674547914087e-4),
+        (3, 0, 0.20481737692309e-7),
+        (3, 1, 0.43870667284435e-6),
+        (3, 3, -0.32277677238570e-4),
+        (3, 6, -0.15033924542148e-2),
+        (3, 35, -0.40668253562649e-1),
+        (4, 1, -0.78847309559367e-9),
+        (4, 2, 0.12790717852285e-7),
+        (4, 3, 0.48225372718507e-6),
+        (5, 7, 0.22922076337661e-5),
+        (6, 3, -0.16714766451061e-10),
+        (6, 16, -0.21171472321355e-2),
+        (6, 35, -0.23895741934104e2),
+        (7, 0, -0.59059564324270e-17),
+        (7, 11, -0.12621808899101e-5),
+        (7, 25, -0.38946842435739e-1),
+        (8, 8, 0.11256211360459e-10),
+        (8, 36, -0.82311340897998e1),
+        (9, 13, 0.19809712802088e-7),
+        (10, 4, 0.10406965210174e-18),
+        (10, 10, -0.10234747095929e-12),
+        (10, 14, -0.10018179379511e-8),
+        (16, 29, -0.80882908646985e-10),
+        (16, 50, 0.10693031879409),
+        (18, 57, -0.33662250574171),
+        (20, 20, 0.89185845355421e-24),
+        (20, 35, 0.30629316876232e-12),
+        (20, 48, -0.42002467698208e-5),
+        (21, 21, -0.59056029685639e-25),
+        (22, 53, 0.37826947613457e-5),
+        (23, 39, -0.12768608934681e-14),
+        (24, 26, 0.73087610595061e-28),
+        (24, 40, 0.55414715350778e-16),
+        (24, 58, -0.94369707241210e-6),
+    ];
+
+    /// The coefficient of the logarithmic term of region 3.
+    pub const REGION_3_LOGARITHMIC: f64 = 0.10658070028513e1;
+
+    pub const REGION_3: &[(i32, i32, f64)] = &[
+        (0, 0, -0.15732845290239e2),
+        (0, 1, 0.20944396974307e2),
+        (0, 2, -0.76867707878716e1),
+        (0, 7, 0.26185947787954e1),
+        (0, 10, -0.28080781148620e1),
+        (0, 12, 0.12053369696517e1),
+        (0, 23, -0.84566812812502e-2),
+        (1, 2, -0.12654315477714e1),
+        (1, 6, -0.11524407806681e1),
+        (1, 15, 0.88521043984318),
+        (1, 17, -0.64207765181607),
+        (2, 0, 0.38493460186671),
+        (2, 2, -0.85214708824206),
+        (2, 6, 0.48972281541877e1),
+        (2, 7, -0.30502617256965e1),
+        (2, 22, 0.39420536879154e-1),
+        (2, 26, 0.12558408424308),
+        (3, 0, -0.27999329698710),
+        (3, 2, 0.13899799569460e1),
+        (3, 4, -0.20189915023570e1),
+        (3, 16, -0.82147637173963e-2),
+        (3, 26, -0.47596035734923),
+        (4, 0, 0.43984074473500e-1),
+        (4, 2, -0.44476435428739),
+        (4, 4, 0.90572070719733),
+        (4, 26, 0.70522450087967),
+        (5, 1, 0.10770512626332),
+        (5, 3, -0.32913623258954),
+        (5, 26, -0.50871062041158),
+        (6, 0, -0.22175400873096e-1),
+        (6, 2, 0.94260751665092e-1),
+        (6, 26, 0.16436278447961),
+        (7, 2, -0.13503372241348e-1),
+        (8, 26, -0.14834345352472e-1),
+        (9, 2, 0.57922953628084e-3),
+        (9, 26, 0.32308904703711e-2),
+        (10, 0, 0.80964802996215e-4),
+        (10, 1, -0.16557679795037e-3),
+        (11, 26, -0.44923899061815e-4),
+    ];
+
+    pub const REGION_4: [f64; 10] = [
+        0.11670521452767e4,
+        -0.72421316703206e6,
+        -0.17073846940092e2,
+        0.12020824702470e5,
+        -0.32325550322333e7,
+        0.14915108613530e2,
+        -0.48232657361591e4,
+        0.40511340542057e6,
+        -0.23855557567849,
+        0.65017534844798e3,
+    ];
+
+    /// Ideal-gas part of region 5, as tuples `(J, n)`.
+    pub const REGION_5_IDEAL: &[(i32, f64)] = &[
+        (0, -0.13179983674201e2),
+        (1, 0.68540841634434e1),
+        (-3, -0.24805148933466e-1),
+        (-2, 0.36901534980333),
+        (-1, -0.31161318213925e1),
+        (2, -0.32961626538917),
+    ];
+
+    pub const REGION_5_RESIDUAL: &[(i32, i32, f64)] = &[
+        (1, 1, 0.15736404855259e-2),
+        (1, 2, 0.90153761673944e-3),
+        (1, 3, -0.50270077677648e-2),
+        (2, 3, 0.22440037409485e-5),
+        (2, 9, -0.41163275453471e-5),
+        (3, 7, 0.37919454822955e-7),
+    ];
+
+    pub const B23: [f64; 5] = [
+        0.34805185628969e3,
+        -0.11671859879975e1,
+        0.10192970039326e-2,
+        0.57254459862746e3,
+        0.13918839778870e2,
+    ];
+
+    pub const B2BC: [f64; 5] = [
+        0.90584278514723e3,
+        -0.67955786399241,
+        0.12809002730136e-3,
+        0.26526571908428e4,
+        0.45257578905948e1,
+    ];
+
+    pub const REGION_1_BACKWARD_PH: &[(i32, i32, f64)] = &[
+        (0, 0, -0.23872489924521e3),
+        (0, 1, 0.40421188637945e3),
+        (0, 2, 0.11349746881718e3),
+        (0, 6, -0.58457616048039e1),
+        (0, 22, -0.15285482413140e-3),
+        (0, 32, -0.10866707695377e-5),
+        (1, 0, -0.13391744872602e2),
+        (1, 1, 0.43211039183559e2),
+        (1, 2, -0.54010067170506e2),
+        (1, 3, 0.30535892203916e2),
+        (1, 4, -0.65964749423638e1),
+        (1, 10, 0.93965400878363e-2),
+        (1, 32, 0.11573647505340e-6),
+        (2, 10, -0.25858641282073e-4),
+        (2, 32, -0.40644363084799e-8),
+        (3, 10, 0.66456186191635e-7),
+        (3, 32, 0.80670734103027e-10),
+        (4, 32, -0.93477771213947e-12),
+        (5, 32, 0.58265442020601e-14),
+        (6, 32, -0.15020185953503e-16),
+    ];
+
+    pub const REGION_1_BACKWARD_PS: &[(i32, i32, f64)] = &[
+        (0, 0, 0.17478268058307e3),
+        (0, 1, 0.34806930892873e2),
+        (0, 2, 0.65292584978455e1),
+        (0, 3, 0.33039981775489),
+        (0, 11, -0.19281382923196e-6),
+        (0, 31, -0.24909197244573e-22),
+        (1, 0, -0.26107636489332),
+        (1, 1, 0.22592965981586),
+        (1, 2, -0.64256463395226e-1),
+        (1, 3, 0.78876289270526e-2),
+        (1, 12, 0.35672110607366e-9),
+        (1, 31, 0.17332496994895e-23),
+        (2, 0, 0.56608900654837e-3),
+        (2, 1, -0.32635483139717e-3),
+        (2, 2, 0.44778286690632e-4),
+        (2, 9, -0.51322156908507e-9),
+        (2, 31, -0.42522657042207e-25),
+        (3, 10, 0.26400441360689e-12),
+        (3, 32, 0.78124600459723e-28),
+        (4, 32, -0.30732199903668e-30),
+    ];
+
+    pub const REGION_2A_BACKWARD_PH: &[(i32, i32, f64)] = &[
+        (0, 0, 0.10898952318288e4),
+        (0, 1, 0.84951654495535e3),
+        (0, 2, -0.10781748091826e3),
+        (0, 3, 0.33153654801263e2),
+        (0, 7, -0.74232016790248e1),
+        (0, 20, 0.11765048724356e2),
+        (1, 0, 0.18445749355790e1),
+        (1, 1, -0.41792700549624e1),
+        (1, 2, 0.62478196935812e1),
+        (1, 3, -0.17344563108114e2),
+        (1, 7, -0.20058176862096e3),
+        (1, 9, 0.27196065473796e3),
+        (1, 11, -0.45511318285818e3),
+        (1, 18, 0.30919688604755e4),
+        (1, 44, 0.25226640357872e6),
+        (2, 0, -0.61707422868339e-2),
+        (2, 2, -0.31078046629583),
+        (2, 7, 0.11670873077107e2),
+        (2, 36, 0.12812798404046e9),
+        (2, 38, -0.98554909623276e9),
+        (2, 40, 0.28224546973002e10),
+        (2, 42, -0.35948971410703e10),
+        (2, 44, 0.17227349913197e10),
+        (3, 24, -0.13551334240775e5),
+        (3, 44, 0.12848734664650e8),
+        (4, 12, 0.13865724283226e1),
+        (4, 32, 0.23598832556514e6),
+        (4, 44, -0.13105236545054e8),
+        (5, 32, 0.73999835474766e4),
+        (5, 36, -0.55196697030060e6),
+        (5, 42, 0.37154085996233e7),
+        (6, 34, 0.19127729239660e5),
+        (6, 44, -0.41535164835634e6),
+        (7, 28, -0.62459855192507e2),
+    ];
+
+    pub const REGION_2B_BACKWARD_PH: &[(i32, i32, f64)] = &[
+        (0, 0, 0.14895041079516e4),
+        (0, 1, 0.74307798314034e3),
+        (0, 2, -0.97708318797837e2),
+        (0, 12, 0.24742464705674e1),
+        (0, 18, -0.63281320016026),
+        (0, 24, 0.11385952129658e1),
+        (0, 28, -0.47811863648625),
+        (0, 40, 0.85208123431544e-2),
+        (1, 0, 0.93747147377932),
+        (1, 2, 0.33593118604916e1),
+        (1, 6, 0.33809355601454e1),
+        (1, 12, 0.16844539671904),
+        (1, 18, 0.73875745236695),
+        (1, 24, -0.47128737436186),
+        (1, 28, 0.15020273139707),
+        (1, 40, -0.21764114219750e-2),
+        (2, 2, -0.21810755324761e-1),
+        (2, 8, -0.10829784403677),
+        (2, 18, -0.46333324635812e-1),
+        (2, 40, 0.71280351959551e-4),
+        (3, 1, 0.11032831789999e-3),
+        (3, 2, 0.18955248387902e-3),
+        (3, 12, 0.30891541160537e-2),
+        (3, 24, 0.13555504554949e-2),
+        (4, 2, 0.28640237477456e-6),
+        (4, 12, -0.10779857357512e-4),
+        (4, 18, -0.76462712454814e-4),
+        (4, 24, 0.14052392818316e-4),
+        (4, 28, -0.31083814331434e-4),
+        (4, 40, -0.10302738212103e-5),
+        (5, 18, 0.28217281635040e-6),
+        (5, 24, 0.12704902271945e-5),
+        (5, 40, 0.73803353468292e-7),
+        (6, 28, -0.11030139238909e-7),
+        (7, 2, -0.81456365207833e-13),
+        (7, 28, -0.25180545682962e-10),
+        (9, 1, -0.17565233969407e-17),
+        (9, 40, 0.86934156344163e-14),
+    ];
+}
@@ -0,0 +1,385 @@
+//! Verification values from the tables of the IAPWS-IF97 release.
+
+use uom::si::{
+    available_energy::kilojoule_per_kilogram,
+    f64::{AvailableEnergy, MassDensity, Pressure, SpecificHeatCapacity, ThermodynamicTemperature},
+    mass_density::kilogram_per_cubic_meter,
+    pressure::megapascal,
+    specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use super::{
+    properties, properties_by_density_temperature, region1,
+    region1_temperature_by_pressure_enthalpy, region1_temperature_by_pressure_entropy, region2,
+    region2_temperature_by_pressure_enthalpy, region3, region5, saturated_liquid, saturated_vapour,
+    saturation_pressure, saturation_temperature, temperature_by_pressure_enthalpy,
+    temperature_by_pressure_entropy, Region,
+};
+
+fn assert_close(actual: f64, expected: f64, relative_tolerance: f64) {
+    assert!(
+        ((actual - expected) / expected).abs() < relative_tolerance,
+        "actual: {actual}, expected: {expected}"
+    );
+}
+
+#[test]
+fn region1_verification() {
+    // (T, p, v, h, u, s, cp, w)
+    for (temperature, pressure, volume, enthalpy, energy, entropy, heat_capacity, sound) in [
+        (
+            300.0,
+            3.0,
+            0.100215168e-2,
+            0.115331273e3,
+            0.112324818e3,
+            0.392294792,
+            0.417301218e1,
+            0.150773921e4,
+        ),
+        (
+            300.0,
+            80.0,
+            0.971180894e-3,
+            0.184142828e3,
+            0.106448356e3,
+            0.368563852,
+            0.401008987e1,
+            0.163469054e4,
+        ),
+        (
+            500.0,
+            3.0,
+            0.120241800e-2,
+            0.975542239e3,
+            0.971934985e3,
+            0.258041912e1,
+            0.465580682e1,
+            0.124071337e4,
+        ),
+    ] {
+        let properties = region1(pressure, temperature);
+        assert_close(1.0 / properties.density, volume, 1e-8);
+        assert_close(properties.specific_enthalpy, enthalpy, 1e-8);
+        assert_close(properties.specific_internal_energy, energy, 1e-8);
+        assert_close(properties.specific_entropy, entropy, 1e-8);
+        assert_close(
+            properties.specific_isobaric_heat_capacity,
+            heat_capacity,
+            1e-8,
+        );
+        assert_close(properties.speed_of_sound, sound, 1e-8);
+    }
+}
+
+#[test]
+fn region2_verification() {
+    for (temperature, pressure, volume, enthalpy, energy, entropy, heat_capacity, sound) in [
+        (
+            300.0,
+            0.0035,
+            0.394913866e2,
+            0.254991145e4,
+            0.241169160e4,
+            0.852238967e1,
+            0.191300162e1,
+            0.427920172e3,
+        ),
+        (
+            700.0,
+            0.0035,
+            0.923015898e2,
+            0.333568375e4,
+            0.301262819e4,
+            0.101749996e2,
+            0.208141274e1,
+            0.644289068e3,
+        ),
+        (
+            700.0,
+            30.0,
+            0.542946619e-2,
+            0.263149474e4,
+            0.246861076e4,
+            0.517540298e1,
+            0.103505092e2,
+            0.480386523e3,
+        ),
+    ] {
+        let properties = region2(pressure, temperature);
+        assert_close(1.0 / properties.density, volume, 1e-8);
+        assert_close(properties.specific_enthalpy, enthalpy, 1e-8);
+        assert_close(properties.specific_internal_energy, energy, 1e-8);
+        assert_close(properties.specific_entropy, entropy, 1e-8);
+        assert_close(
+            properties.specific_isobaric_heat_capacity,
+            heat_capacity,
+            1e-8,
+        );
+        assert_close(properties.speed_of_sound, sound, 1e-8);
+    }
+}
+
+#[test]
+fn region3_verification() {
+    for (temperature, density, pressure, enthalpy, energy, entropy, heat_capacity, sound) in [
+        (
+            650.0,
+            500.0,
+            0.255837018e2,
+            0.186343019e4,
+            0.181226279e4,
+            0.405427273e1,
+            0.138935717e2,
+            0.502005554e3,
+        ),
+        (
+            650.0,
+            200.0,
+            0.222930643e2,
+            0.237512401e4,
+            0.226365868e4,
+            0.485438792e1,
+            0.446579342e2,
+            0.383444594e3,
+        ),
+        (
+            750.0,
+            500.0,
+            0.783095639e2,
+            0.225868845e4,
+            0.210206932e4,
+            0.446971906e1,
+            0.634165359e1,
+            0.760696041e3,
+        ),
+    ] {
+        let properties = region3(density, temperature);
+        assert_close(properties.pressure, pressure, 1e-8);
+        assert_close(properties.specific_enthalpy, enthalpy, 1e-8);
+        assert_close(properties.specific_internal_energy, energy, 1e-8);
+        assert_close(properties.specific_entropy, entropy, 1e-8);
+        assert_close(
+            properties.specific_isobaric_heat_capacity,
+            heat_capacity,
+            1e-8,
+        );
+        assert_close(properties.speed_of_sound, sound, 1e-8);
+    }
+}
+
+#[test]
+fn region4_verification() {
+    for (temperature, pressure) in [
+        (300.0, 0.353658941e-2),
+        (500.0, 0.263889776e1),
+        (600.0, 0.123443146e2),
+    ] {
+        let actual = saturation_pressure(ThermodynamicTemperature::new::<kelvin>(temperature))
+            .unwrap()
+            .get::<megapascal>();
+        assert_close(actual, pressure, 1e-8);
+    }
+
+    for (pressure, temperature) in [
+        (0.1, 0.372755919e3),
+        (1.0, 0.453035632e3),
+        (10.0, 0.584149488e3),
+    ] {
+        let actual = saturation_temperature(Pressure::new::<megapascal>(pressure))
+            .unwrap()
+            .get::<kelvin>();
+        assert_close(actual, temperature, 1e-8);
+    }
+}
+
+#[test]
+fn region5_verification() {
+    for (temperature, pressure, volume, enthalpy, energy, entropy, heat_capacity, sound) in [
+        (
+            1500.0,
+            0.5,
+            0.138455090e1,
+            0.521976855e4,
+            0.452749310e4,
+            0.965408875e1,
+            0.261609445e1,
+            0.917068690e3,
+        ),
+        (
+            1500.0,
+            30.0,
+            0.230761299e-1,
+            0.516723514e4,
+            0.447495124e4,
+            0.772970133e1,
+            0.272724317e1,
+            0.928548002e3,
+        ),
+        (
+            2000.0,
+            30.0,
+            0.311385219e-1,
+            0.657122604e4,
+            0.563707038e4,
+            0.853640523e1,
+            0.288569882e1,
+            0.106736948e4,
+        ),
+    ] {
+        let properties = region5(pressure, temperature);
+        assert_close(1.0 / properties.density, volume, 1e-8);
+        assert_close(properties.specific_enthalpy, enthalpy, 1e-8);
+        assert_close(properties.specific_internal_energy, energy, 1e-8);
+        assert_close(properties.specific_entropy, entropy, 1e-8);
+        assert_close(
+            properties.specific_isobaric_heat_capacity,
+            heat_capacity,
+            1e-8,
+        );
+        assert_close(properties.speed_of_sound, sound, 1e-8);
+    }
+}
+
+#[test]
+fn backward_equations_verification() {
+    for (pressure, enthalpy, temperature) in [
+        (3.0, 500.0, 0.391798509e3),
+        (80.0, 500.0, 0.378108626e3),
+        (80.0, 1500.0, 0.611041229e3),
+    ] {
+        assert_close(
+            region1_temperature_by_pressure_enthalpy(pressure, enthalpy),
+            temperature,
+            1e-8,
+        );
+    }
+
+    for (pressure, entropy, temperature) in [
+        (3.0, 0.5, 0.307842258e3),
+        (80.0, 0.5, 0.309979785e3),
+        (80.0, 3.0, 0.565899909e3),
+    ] {
+        assert_close(
+            region1_temperature_by_pressure_entropy(pressure, entropy),
+            temperature,
+            1e-8,
+        );
+    }
+
+    for (pressure, enthalpy, temperature) in [
+        (0.001, 3000.0, 0.534433241e3),
+        (3.0, 3000.0, 0.575373370e3),
+        (3.0, 4000.0, 0.101077577e4),
+        (5.0, 3500.0, 0.801299102e3),
+        (5.0, 4000.0, 0.101531583e4),
+        (25.0, 3500.0, 0.875279054e3),
+    ] {
+        assert_close(
+            region2_temperature_by_pressure_enthalpy(pressure, enthalpy).unwrap(),
+            temperature,
+            1e-8,
+        );
+    }
+}
+
+#[test]
+fn round_trips() {
+    for (pressure, temperature, region) in [
+        (40.0, 743.056411, Region::Region2),
+        (3.0, 300.0, Region::Region1),
+        (0.0035, 700.0, Region::Region2),
+        (30.0, 700.0, Region::Region2),
+        (25.5837018, 650.0, Region::Region3),
+        (22.2930643, 650.0, Region::Region3),
+        (78.3095639, 750.0, Region::Region3),
+        (19.0, 640.0, Region::Region3),
+        (30.0, 1500.0, Region::Region5),
+    ] {
+        let pressure = Pressure::new::<megapascal>(pressure);
+        let temperature = ThermodynamicTemperature::new::<kelvin>(temperature);
+        let forward = properties(pressure, temperature).unwrap();
+        assert_eq!(forward.region(), region);
+
+        let by_density = properties_by_density_temperature(forward.density(), temperature).unwrap();
+        assert_close(
+            by_density.pressure().get::<megapascal>(),
+            pressure.get::<megapascal>(),
+            1e-8,
+        );
+
+        let by_enthalpy =
+            temperature_by_pressure_enthalpy(pressure, forward.specific_enthalpy()).unwrap();
+        assert_close(
+            by_enthalpy.get::<kelvin>(),
+            temperature.get::<kelvin>(),
+            1e-8,
+        );
+
+        let by_entropy =
+            temperature_by_pressure_entropy(pressure, forward.specific_entropy()).unwrap();
+        assert_close(
+            by_entropy.get::<kelvin>(),
+            temperature.get::<kelvin>(),
+            1e-8,
+        );
+    }
+}
+
+#[test]
+fn saturation_line() {
+    for temperature in [280.0, 373.15, 500.0, 623.15, 630.0, 640.0, 647.0] {
+        let temperature = ThermodynamicTemperature::new::<kelvin>(temperature);
+        let liquid = saturated_liquid(temperature).unwrap();
+        let vapour = saturated_vapour(temperature).unwrap();
+        let pressure = saturation_pressure(temperature).unwrap();
+
+        assert!(liquid.density() > vapour.density());
+        assert!(liquid.specific_enthalpy() < vapour.specific_enthalpy());
+        assert_close(
+            liquid.pressure().get::<megapascal>(),
+            pressure.get::<megapascal>(),
+            1e-3,
+        );
+        assert_close(
+            vapour.pressure().get::<megapascal>(),
+            pressure.get::<megapascal>(),
+            1e-3,
+        );
+    }
+
+    // Inside the two-phase region, the temperature is the saturation temperature.
+    let pressure = Pressure::new::<megapascal>(1.0);
+    let temperature = temperature_by_pressure_enthalpy(
+        pressure,
+        AvailableEnergy::new::<kilojoule_per_kilogram>(1500.0),
+    )
+    .unwrap();
+    assert_close(temperature.get::<kelvin>(), 0.453035632e3, 1e-8);
+
+    assert!(properties_by_density_temperature(
+        MassDensity::new::<kilogram_per_cubic_meter>(100.0),
+        ThermodynamicTemperature::new::<kelvin>(400.0),
+    )
+    .is_err());
+}
+
+#[test]
+fn out_of_range() {
+    assert!(properties(
+        Pressure::new::<megapascal>(101.0),
+        ThermodynamicTemperature::new::<kelvin>(400.0)
+    )
+    .is_err());
+    assert!(properties(
+        Pressure::new::<megapascal>(60.0),
+        ThermodynamicTemperature::new::<kelvin>(1500.0)
+    )
+    .is_err());
+    assert!(temperature_by_pressure_entropy(
+        Pressure::new::<megapascal>(1.0),
+        SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(-1.0)
+    )
+    .is_err());
+}
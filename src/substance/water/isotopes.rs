@@ -0,0 +1,160 @@
+//! Radioactive isotopes carried by reactor coolant.
+
+use uom::num_traits::Zero;
+use uom::si::f64::{Mass, Radioactivity, Ratio, SpecificRadioactivity, Time};
+use uom::si::mass::kilogram;
+use uom::si::radioactivity::becquerel;
+use uom::si::ratio::ratio;
+use uom::si::specific_radioactivity::becquerel_per_kilogram;
+use uom::si::time::{day, second, year};
+
+use crate::substance::water::partition_by_evaporation;
+
+#[cfg(test)]
+mod tests;
+
+/// A radioactive isotope found in reactor coolant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Isotope {
+    /// Activation product of oxygen in the coolant.
+    Nitrogen16,
+    /// Activation product of boron, lithium and deuterium in the coolant.
+    /// It forms tritiated water, so it evaporates with the water.
+    Tritium,
+    /// Fission product noble gas.
+    Krypton85,
+    /// Fission product noble gas.
+    Xenon133,
+    Iodine131,
+    Caesium137,
+}
+
+/// The specific activities of the isotopes in some water.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IsotopeInventory {
+    #[cfg_attr(feature = "serde", serde(rename = "specific_activities_bq_per_kg"))]
+    specific_activities: [SpecificRadioactivity; 6],
+}
+
+impl Isotope {
+    pub const ALL: [Isotope; 6] = [
+        Isotope::Nitrogen16,
+        Isotope::Tritium,
+        Isotope::Krypton85,
+        Isotope::Xenon133,
+        Isotope::Iodine131,
+        Isotope::Caesium137,
+    ];
+
+    pub fn half_life(self) -> Time {
+        match self {
+            Isotope::Nitrogen16 => Time::new::<second>(7.13),
+            Isotope::Tritium => Time::new::<year>(12.32),
+            Isotope::Krypton85 => Time::new::<year>(10.76),
+            Isotope::Xenon133 => Time::new::<day>(5.2475),
+            Isotope::Iodine131 => Time::new::<day>(8.0252),
+            Isotope::Caesium137 => Time::new::<year>(30.08),
+        }
+    }
+
+    /// Whether this isotope is released from the water when it evaporates.
+    pub fn is_volatile(self) -> bool {
+        self.partition_coefficient() > 0.0
+    }
+
+    /// The ratio of the concentration in steam to the concentration in water.
+    /// Noble gases behave like other dissolved gases, and iodine is mostly kept in solution as iodide.
+    fn partition_coefficient(self) -> f64 {
+        match self {
+            Isotope::Nitrogen16 | Isotope::Caesium137 => 0.0,
+            Isotope::Tritium => 1.0,
+            Isotope::Krypton85 | Isotope::Xenon133 => 300.0,
+            Isotope::Iodine131 => 0.01,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Isotope::Nitrogen16 => 0,
+            Isotope::Tritium => 1,
+            Isotope::Krypton85 => 2,
+            Isotope::Xenon133 => 3,
+            Isotope::Iodine131 => 4,
+            Isotope::Caesium137 => 5,
+        }
+    }
+}
+
+impl IsotopeInventory {
+    /// No radioactivity.
+    pub fn zero() -> Self {
+        Self {
+            specific_activities: [SpecificRadioactivity::zero(); 6],
+        }
+    }
+
+    /// Add the given specific activity of an isotope.
+    pub fn with(self, isotope: Isotope, specific_activity: SpecificRadioactivity) -> Self {
+        let mut specific_activities = self.specific_activities;
+        specific_activities[isotope.index()] += specific_activity;
+        Self {
+            specific_activities,
+        }
+    }
+
+    pub fn specific_activity(&self, isotope: Isotope) -> SpecificRadioactivity {
+        self.specific_activities[isotope.index()]
+    }
+
+    /// The specific activity of all isotopes together.
+    pub fn total_specific_activity(&self) -> SpecificRadioactivity {
+        self.specific_activities.iter().copied().sum()
+    }
+
+    /// The activity of the given isotope in the given mass of water.
+    pub fn activity(&self, isotope: Isotope, mass: Mass) -> Radioactivity {
+        Radioactivity::new::<becquerel>(
+            self.specific_activity(isotope)
+                .get::<becquerel_per_kilogram>()
+                * mass.get::<kilogram>(),
+        )
+    }
+
+    /// Let the isotopes decay for the given time.
+    pub fn decay(&mut self, time: Time) {
+        for isotope in Isotope::ALL {
+            let half_lives = (time / isotope.half_life()).get::<ratio>();
+            self.specific_activities[isotope.index()] *= 0.5_f64.powf(half_lives);
+        }
+    }
+
+    /// Mix two amounts of water with the given mass fractions.
+    pub(super) fn mix(self, self_fraction: f64, rhs: Self, rhs_fraction: f64) -> Self {
+        let mut specific_activities = self.specific_activities;
+        for (specific_activity, rhs_specific_activity) in
+            specific_activities.iter_mut().zip(rhs.specific_activities)
+        {
+            *specific_activity =
+                *specific_activity * self_fraction + rhs_specific_activity * rhs_fraction;
+        }
+        Self {
+            specific_activities,
+        }
+    }
+
+    /// Evaporate the given fraction of the mass of some water with this inventory.
+    /// Returns the inventory of the steam, and updates this to the inventory of the remaining water.
+    pub(super) fn evaporate(&mut self, evaporated_fraction: Ratio) -> Self {
+        let evaporated_fraction = evaporated_fraction.get::<ratio>();
+        let mut steam = Self::zero();
+        for isotope in Isotope::ALL {
+            let (water_factor, steam_factor) =
+                partition_by_evaporation(evaporated_fraction, isotope.partition_coefficient());
+            let specific_activity = &mut self.specific_activities[isotope.index()];
+            steam.specific_activities[isotope.index()] = *specific_activity * steam_factor;
+            *specific_activity *= water_factor;
+        }
+        steam
+    }
+}
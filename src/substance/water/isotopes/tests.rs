@@ -0,0 +1,74 @@
+use uom::si::{
+    f64::{Ratio, SpecificRadioactivity, Time},
+    ratio::ratio,
+    specific_radioactivity::becquerel_per_kilogram,
+    time::{day, second},
+};
+
+use super::{Isotope, IsotopeInventory};
+
+fn inventory() -> IsotopeInventory {
+    Isotope::ALL
+        .iter()
+        .fold(IsotopeInventory::zero(), |inventory, isotope| {
+            inventory.with(
+                *isotope,
+                SpecificRadioactivity::new::<becquerel_per_kilogram>(1e6),
+            )
+        })
+}
+
+#[test]
+fn decay() {
+    let mut inventory = inventory();
+    inventory.decay(Time::new::<second>(7.13));
+    let nitrogen = inventory
+        .specific_activity(Isotope::Nitrogen16)
+        .get::<becquerel_per_kilogram>();
+    assert!((nitrogen - 5e5).abs() < 1e-6);
+
+    inventory.decay(Time::new::<day>(8.0252));
+    let iodine = inventory
+        .specific_activity(Isotope::Iodine131)
+        .get::<becquerel_per_kilogram>();
+    assert!((iodine - 5e5).abs() < 50.0);
+    let caesium = inventory
+        .specific_activity(Isotope::Caesium137)
+        .get::<becquerel_per_kilogram>();
+    assert!(caesium > 0.999e6);
+}
+
+#[test]
+fn evaporate() {
+    let original = inventory();
+    let mut water = original;
+    let fraction = 0.05;
+    let steam = water.evaporate(Ratio::new::<ratio>(fraction));
+
+    for isotope in Isotope::ALL {
+        let before = original
+            .specific_activity(isotope)
+            .get::<becquerel_per_kilogram>();
+        let after = (water.specific_activity(isotope) * (1.0 - fraction)
+            + steam.specific_activity(isotope) * fraction)
+            .get::<becquerel_per_kilogram>();
+        assert!((before - after).abs() < 1e-6, "{isotope:?}");
+        assert_eq!(
+            isotope.is_volatile(),
+            steam.specific_activity(isotope)
+                > SpecificRadioactivity::new::<becquerel_per_kilogram>(0.0)
+        );
+    }
+
+    // Noble gases are stripped, tritium evaporates with the water.
+    assert!(
+        water.specific_activity(Isotope::Xenon133)
+            < original.specific_activity(Isotope::Xenon133) * 1e-3
+    );
+    assert!(
+        (steam.specific_activity(Isotope::Tritium) - original.specific_activity(Isotope::Tritium))
+            .get::<becquerel_per_kilogram>()
+            .abs()
+            < 1e-6
+    );
+}
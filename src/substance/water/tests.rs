@@ -1,10 +1,44 @@
 use uom::si::{
-    f64::{Mass, ThermodynamicTemperature},
+    angle::radian,
+    angular_velocity::radian_per_second,
+    available_energy::kilojoule_per_kilogram,
+    energy::{joule, megajoule},
+    f64::{
+        Angle, AngularVelocity, AvailableEnergy, Energy, MagneticFlux, Mass, MassDensity, MassRate,
+        MomentOfInertia, Power, Pressure, Ratio, SpecificRadioactivity, ThermodynamicTemperature,
+        Time, Volume,
+    },
+    heat_capacity::kilojoule_per_kelvin,
+    magnetic_flux::weber,
     mass::kilogram,
-    thermodynamic_temperature::kelvin,
+    mass_density::kilogram_per_cubic_meter,
+    mass_rate::kilogram_per_second,
+    moment_of_inertia::kilogram_square_meter,
+    power::megawatt,
+    pressure::bar,
+    radioactivity::becquerel,
+    ratio::{part_per_million, ratio},
+    specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+    specific_radioactivity::becquerel_per_kilogram,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    time::second,
+    volume::cubic_meter,
 };
 
-use super::Water;
+use super::{
+    chemistry::Chemistry,
+    constants::BOILING_POINT_BY_PRESSURE_RAW,
+    critical_temperature,
+    equation_of_state::SteamEquationOfState,
+    flash, fusion_energy, ice_density,
+    isotopes::{Isotope, IsotopeInventory},
+    melting_point, partition_by_evaporation, phase_change_energy, Phase, PropertyBackend, Water,
+};
+use crate::error::Error;
+use crate::interpolation_table::{InterpolationKind, LimitBehaviour, LinearInterpolationTable};
+use crate::substance::Substance;
+use crate::synchronous_machine::SynchronousMachine;
+use uom::num_traits::Zero;
 
 #[test]
 fn add() {
@@ -15,12 +49,20 @@ fn add() {
 
     let water2 = Water::new(
         Mass::new::<kilogram>(2.0),
-        ThermodynamicTemperature::new::<kelvin>(100.0),
+        ThermodynamicTemperature::new::<kelvin>(300.0),
     );
 
     let water_sum = water1 + water2;
     assert!((water_sum.mass().get::<kilogram>() - 3.0).abs() < 1e-10);
-    assert!((water_sum.temperature().get::<kelvin>() - 200.0).abs() < 1e-10);
+    // The heat capacity rises with temperature, so the mix is slightly warmer than the mass-weighted mean.
+    assert!(water_sum.temperature().get::<kelvin>() > 1000.0 / 3.0);
+    assert!(water_sum.temperature().get::<kelvin>() < 335.0);
+    assert!(
+        (water_sum.enthalpy() - water1.enthalpy() - water2.enthalpy())
+            .abs()
+            .get::<joule>()
+            < 1e-6
+    );
 }
 
 #[test]
@@ -39,3 +81,625 @@ fn add_zero() {
     assert!((water_sum.mass().get::<kilogram>() - 2.0).abs() < 1e-10);
     assert!((water_sum.temperature().get::<kelvin>() - 100.0).abs() < 1e-10);
 }
+
+#[test]
+fn iapws_if97_steam_pressure() {
+    // Saturated steam at 150 bar has a density of about 96.7 kg/m^3, at a temperature of about 615.3 K.
+    let steam = Water::new_steam(
+        Mass::new::<kilogram>(96.71),
+        ThermodynamicTemperature::new::<kelvin>(615.31),
+    );
+    let volume = Volume::new::<cubic_meter>(1.0);
+
+    let approximate_pressure = steam.pressure(volume).get::<bar>();
+    let iapws_if97_pressure = steam
+        .with_property_backend(PropertyBackend::IapwsIf97)
+        .pressure(volume)
+        .get::<bar>();
+
+    assert!((iapws_if97_pressure - 150.0).abs() < 0.5);
+    assert!(approximate_pressure > 250.0);
+}
+
+#[test]
+fn iapws_if97_liquid_volume() {
+    let water = Water::new(
+        Mass::new::<kilogram>(1000.0),
+        ThermodynamicTemperature::new::<degree_celsius>(25.0),
+    )
+    .with_property_backend(PropertyBackend::IapwsIf97);
+    assert!((water.volume().get::<cubic_meter>() - 1.003).abs() < 1e-3);
+
+    let saturation_pressure = water.saturation_pressure().get::<bar>();
+    assert!((saturation_pressure - 0.03170).abs() < 1e-4);
+}
+
+#[test]
+fn iapws_if97_clamps() {
+    let backend = PropertyBackend::IapwsIf97;
+    for temperature in [-50.0, 0.0, 373.946, 1000.0, 5000.0] {
+        let temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature);
+        assert!(backend.liquid_density(temperature).is_finite());
+        assert!(backend.saturation_pressure(temperature).is_finite());
+        assert!(backend
+            .vapour_pressure(
+                SteamEquationOfState::IdealGas,
+                Mass::new::<kilogram>(100.0),
+                temperature,
+                Volume::new::<cubic_meter>(1.0),
+            )
+            .is_finite());
+    }
+    assert_eq!(
+        backend.saturation_pressure(ThermodynamicTemperature::new::<degree_celsius>(5000.0)),
+        backend.saturation_pressure(critical_temperature())
+    );
+    for pressure in [0.0, 1e-3, 1.0, 220.64, 1000.0] {
+        let boiling_point = backend.boiling_point(Pressure::new::<bar>(pressure));
+        let boiling_point = boiling_point.get::<kelvin>();
+        assert!((273.15..647.1).contains(&boiling_point), "{boiling_point}");
+    }
+}
+
+#[test]
+fn heat_capacity() {
+    let cold = Water::new(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(25.0),
+    );
+    let hot = Water::new(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(340.0),
+    );
+    let steam = Water::new_steam(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(100.0),
+    );
+
+    let heat_capacity = |water: Water| water.heat_capacity().get::<kilojoule_per_kilogram_kelvin>();
+    assert!((heat_capacity(cold) - 4.18).abs() < 0.01);
+    assert!(heat_capacity(hot) > 6.0);
+    assert!((heat_capacity(steam) - 1.9).abs() < 0.05);
+
+    let heated = hot + Energy::new::<joule>(100e3);
+    assert!(heated.temperature().get::<degree_celsius>() < 360.0);
+}
+
+#[test]
+fn evaporate_condensate_conserves_energy() {
+    let mut water = Water::new(
+        Mass::new::<kilogram>(10.0),
+        ThermodynamicTemperature::new::<degree_celsius>(250.0),
+    );
+    let enthalpy = water.enthalpy();
+
+    let mut steam = water.evaporate(Mass::new::<kilogram>(1.0)).unwrap();
+    assert_eq!(steam.phase(), Phase::Vapour);
+    assert!(
+        (water.temperature().get::<kelvin>() - steam.temperature().get::<kelvin>()).abs() < 1e-6
+    );
+    assert!(water.temperature().get::<degree_celsius>() < 250.0);
+    assert!(
+        ((water.enthalpy() + steam.enthalpy()) - enthalpy)
+            .abs()
+            .get::<joule>()
+            < 1e-3
+    );
+
+    let condensate = steam.condensate(Mass::new::<kilogram>(0.1));
+    assert_eq!(condensate.phase(), Phase::Liquid);
+    assert!(
+        (condensate.temperature().get::<kelvin>() - steam.temperature().get::<kelvin>()).abs()
+            < 1e-6
+    );
+    assert!(steam.temperature() > water.temperature());
+
+    let water = water + condensate;
+    assert!(
+        ((water.enthalpy() + steam.enthalpy()) - enthalpy)
+            .abs()
+            .get::<joule>()
+            < 1e-3
+    );
+}
+
+#[test]
+fn supercritical() {
+    let water = Water::new(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(350.0),
+    );
+    assert_eq!(water.phase(), Phase::Liquid);
+
+    let water = water + Energy::new::<joule>(1e6);
+    assert_eq!(water.phase(), Phase::Supercritical);
+    assert!(water.temperature() > critical_temperature());
+
+    // The supercritical fluid has no saturation line to fall back on, so its pressure depends on its density.
+    let pressure = water.pressure(Volume::new::<cubic_meter>(0.004));
+    assert!(pressure.get::<bar>() > 220.64);
+}
+
+#[test]
+fn bulk_modulus() {
+    for temperature in [20.0, 150.0, 300.0] {
+        let temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature);
+        let approximate = PropertyBackend::Approximate.bulk_modulus(temperature);
+        let iapws_if97 = PropertyBackend::IapwsIf97.bulk_modulus(temperature);
+        assert!(((approximate / iapws_if97).get::<ratio>() - 1.0).abs() < 0.01);
+    }
+
+    // Compressing liquid water by one percent at room temperature takes about 220 bar.
+    let water = Water::new(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+    );
+    let pressure = water.liquid_pressure(water.volume() / 1.01);
+    assert!((pressure.get::<bar>() - 218.0).abs() < 5.0, "{pressure:?}");
+}
+
+#[test]
+fn boron() {
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(280.0);
+    let borated = Water::new(Mass::new::<kilogram>(1.0), temperature)
+        .with_boron_concentration(Ratio::new::<part_per_million>(1000.0));
+    let clean = Water::new(Mass::new::<kilogram>(3.0), temperature);
+
+    let mut water = borated + clean;
+    assert!((water.boron_concentration().get::<part_per_million>() - 250.0).abs() < 1e-9);
+
+    let steam = water.evaporate(Mass::new::<kilogram>(0.5)).unwrap();
+    assert!(steam.boron_concentration().is_zero());
+    assert!(steam
+        .with_boron_concentration(Ratio::new::<part_per_million>(1000.0))
+        .boron_concentration()
+        .is_zero());
+    assert!((water.boron_mass().get::<kilogram>() - 1e-3).abs() < 1e-12);
+    assert!(water.boron_concentration().get::<part_per_million>() > 250.0);
+}
+
+#[test]
+fn chemistry() {
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(180.0);
+    let mut water =
+        Water::new(Mass::new::<kilogram>(1000.0), temperature).with_chemistry(Chemistry {
+            dissolved_oxygen: Ratio::new::<part_per_million>(8.0),
+            ..Chemistry::zero()
+        });
+
+    // Deaerate the water by boiling off some steam.
+    let mut steam = water.evaporate(Mass::new::<kilogram>(10.0)).unwrap();
+    assert!(water.chemistry().dissolved_oxygen < Ratio::new::<part_per_million>(0.5));
+    let oxygen = |water: &Water| water.mass() * water.chemistry().dissolved_oxygen;
+    assert!(
+        ((oxygen(&water) + oxygen(&steam)) - Mass::new::<kilogram>(8e-3))
+            .abs()
+            .get::<kilogram>()
+            < 1e-12
+    );
+
+    // The condensate takes the gas with it.
+    let condensate = steam.condensate(Mass::new::<kilogram>(1.0));
+    assert_eq!(condensate.chemistry(), steam.chemistry());
+    let water = water + condensate;
+    assert!(
+        ((oxygen(&water) + oxygen(&steam)) - Mass::new::<kilogram>(8e-3))
+            .abs()
+            .get::<kilogram>()
+            < 1e-12
+    );
+}
+
+#[test]
+fn isotopes() {
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(290.0);
+    let mut water = Water::new(Mass::new::<kilogram>(100.0), temperature).with_isotopes(
+        IsotopeInventory::zero().with(
+            Isotope::Caesium137,
+            SpecificRadioactivity::new::<becquerel_per_kilogram>(1e3),
+        ),
+    );
+    let clean = Water::new(Mass::new::<kilogram>(100.0), temperature);
+
+    // Removed water takes its share of the activity along.
+    let removed = water.remove(Mass::new::<kilogram>(50.0));
+    let activity = |water: &Water| water.activity(Isotope::Caesium137).get::<becquerel>();
+    assert!((activity(&removed) - 5e4).abs() < 1e-6);
+    assert!((activity(&water) - 5e4).abs() < 1e-6);
+
+    // Mixing conserves the activity.
+    let water = water + clean;
+    assert!((activity(&water) - 5e4).abs() < 1e-6);
+    assert!(
+        (water
+            .isotopes()
+            .specific_activity(Isotope::Caesium137)
+            .get::<becquerel_per_kilogram>()
+            - 1e3 / 3.0)
+            .abs()
+            < 1e-9
+    );
+}
+
+#[test]
+fn evaporate_completely() {
+    // Nothing remains to hold the solutes, so the steam carries all of them.
+    assert_eq!(partition_by_evaporation(1.0, 0.0), (1.0, 1.0));
+    assert_eq!(partition_by_evaporation(1.0, 20.0), (1.0, 1.0));
+
+    let lithium = Ratio::new::<part_per_million>(2.0);
+    let mut chemistry = Chemistry {
+        lithium,
+        ..Chemistry::zero()
+    };
+    assert_eq!(
+        chemistry.evaporate(Ratio::new::<ratio>(1.0)).lithium,
+        lithium
+    );
+
+    let specific_activity = SpecificRadioactivity::new::<becquerel_per_kilogram>(1e3);
+    let mut isotopes = IsotopeInventory::zero().with(Isotope::Caesium137, specific_activity);
+    let steam_isotopes = isotopes.evaporate(Ratio::new::<ratio>(1.0));
+    assert_eq!(
+        steam_isotopes.specific_activity(Isotope::Caesium137),
+        specific_activity
+    );
+}
+
+#[test]
+fn latent_heat() {
+    let latent_heat = |temperature| {
+        phase_change_energy(ThermodynamicTemperature::new::<degree_celsius>(temperature))
+            .get::<kilojoule_per_kilogram>()
+    };
+    assert!((latent_heat(100.0) - 2256.5).abs() < 0.1);
+    assert!(latent_heat(300.0) < 1405.0);
+    assert_eq!(latent_heat(380.0), 0.0);
+
+    // Close to the critical point, much more water evaporates when cooling down by the same amount.
+    let evaporable = |temperature: f64| {
+        Water::new(
+            Mass::new::<kilogram>(1.0),
+            ThermodynamicTemperature::new::<degree_celsius>(temperature),
+        )
+        .maximum_evaporable_amount(ThermodynamicTemperature::new::<degree_celsius>(
+            temperature - 10.0,
+        ))
+        .get::<kilogram>()
+    };
+    assert!(evaporable(360.0) > 5.0 * evaporable(110.0));
+
+    // Evaporating takes the latent heat at the final temperature, conserving energy.
+    let mut water = Water::new(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(360.0),
+    );
+    let enthalpy = water.enthalpy();
+    let steam = water.evaporate(Mass::new::<kilogram>(0.1)).unwrap();
+    assert!(
+        ((water.enthalpy() + steam.enthalpy()) - enthalpy)
+            .abs()
+            .get::<joule>()
+            < 1e-3
+    );
+    let released =
+        (steam.specific_enthalpy() - water.specific_enthalpy()).get::<kilojoule_per_kilogram>();
+    assert!(
+        (released - phase_change_energy(water.temperature()).get::<kilojoule_per_kilogram>()).abs()
+            < 1e-3
+    );
+}
+
+#[test]
+fn ice() {
+    let mass = Mass::new::<kilogram>(1.0);
+    let mut water = Water::new(mass, ThermodynamicTemperature::new::<degree_celsius>(-10.0));
+    assert_eq!(water.phase(), Phase::Ice);
+    assert_eq!(water.ice_mass(), mass);
+    assert!((water.heat_capacity().get::<kilojoule_per_kilogram_kelvin>() - 2.0).abs() < 0.1);
+    assert!(
+        (water.density() - ice_density()).abs() < MassDensity::new::<kilogram_per_cubic_meter>(1.0)
+    );
+
+    // Melting takes the latent heat of fusion, while the temperature stays at the melting point.
+    let frozen_at_melting_point =
+        Water::new(mass, melting_point()).specific_enthalpy() - fusion_energy();
+    water += (frozen_at_melting_point - water.specific_enthalpy()) * mass;
+    assert_eq!(water.phase(), Phase::Ice);
+    water += fusion_energy() * mass * 0.5;
+    assert_eq!(water.phase(), Phase::Liquid);
+    assert!((water.temperature().get::<degree_celsius>() - 0.0).abs() < 1e-9);
+    assert!((water.ice_mass().get::<kilogram>() - 0.5).abs() < 1e-9);
+
+    // Partially frozen water is lighter than liquid water.
+    let liquid = Water::new(mass, melting_point());
+    assert!(water.density() < liquid.density());
+    assert!(water.density() > ice_density());
+
+    water += fusion_energy() * mass * 0.5;
+    assert!(water.ice_mass().get::<kilogram>() < 1e-9);
+    assert!(water.temperature().get::<degree_celsius>() < 0.1);
+
+    // Freezing releases the same heat again.
+    water -= fusion_energy() * mass;
+    assert!((water.ice_mass().get::<kilogram>() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn entropy() {
+    // Saturated steam at 70 bar has 5.8148 kJ/(kg K).
+    let pressure = Pressure::new::<bar>(70.0);
+    let mut steam = Water::new_steam(
+        Mass::new::<kilogram>(2.0),
+        ThermodynamicTemperature::new::<degree_celsius>(285.83),
+    );
+    let specific_entropy = steam
+        .specific_entropy(pressure)
+        .unwrap()
+        .get::<kilojoule_per_kilogram_kelvin>();
+    assert!(
+        (specific_entropy - 5.8148).abs() < 0.02,
+        "{specific_entropy}"
+    );
+    assert!(
+        (steam
+            .entropy(pressure)
+            .unwrap()
+            .get::<kilojoule_per_kelvin>()
+            - 2.0 * specific_entropy)
+            .abs()
+            < 1e-9
+    );
+
+    // Expanding through a turbine takes the enthalpy drop from the steam.
+    let enthalpy = steam.enthalpy();
+    let expansion = steam
+        .expand(
+            pressure,
+            Pressure::new::<bar>(10.0),
+            Some(Ratio::new::<ratio>(0.9)),
+        )
+        .unwrap();
+    assert!(expansion.enthalpy_drop() > AvailableEnergy::zero());
+    assert!(
+        ((enthalpy - steam.enthalpy()) - expansion.enthalpy_drop() * steam.mass())
+            .abs()
+            .get::<joule>()
+            < 1e-6
+    );
+    // The moist steam at 10 bar is at the saturation temperature of 179.88°C.
+    assert_eq!(steam.phase(), Phase::Vapour);
+    assert!(expansion.outlet().quality().is_some());
+    assert!((steam.temperature().get::<degree_celsius>() - 179.88).abs() < 0.01);
+    assert!(
+        (steam.temperature().get::<kelvin>() - expansion.outlet().temperature().get::<kelvin>())
+            .abs()
+            < 1e-6
+    );
+    assert!(
+        (steam.specific_entropy(Pressure::new::<bar>(10.0)).unwrap()
+            - expansion.outlet().specific_entropy())
+        .abs()
+        .get::<kilojoule_per_kilogram_kelvin>()
+            < 1e-9
+    );
+    assert!(
+        steam
+            .specific_entropy(Pressure::new::<bar>(10.0))
+            .unwrap()
+            .get::<kilojoule_per_kilogram_kelvin>()
+            > specific_entropy
+    );
+}
+
+#[test]
+fn wet_exhaust_drives_generator() {
+    let inlet_pressure = Pressure::new::<bar>(70.0);
+    let condenser = Pressure::new::<bar>(0.05);
+    let mut steam = Water::new_steam(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(285.83),
+    );
+    let expansion = steam
+        .expand(inlet_pressure, condenser, Some(Ratio::new::<ratio>(0.85)))
+        .unwrap();
+
+    // The exhaust is wet, but stays steam until the moisture condenses in a container.
+    assert_eq!(steam.phase(), Phase::Vapour);
+    let quality = flash::by_pressure_enthalpy(condenser, steam.specific_enthalpy())
+        .unwrap()
+        .quality()
+        .unwrap()
+        .get::<ratio>();
+    assert!(quality > 0.5 && quality < 1.0, "{quality}");
+
+    // The shaft power that is not taken by the electrical load accelerates the generator.
+    let mut machine = SynchronousMachine::new(
+        MomentOfInertia::new::<kilogram_square_meter>(1.0e5),
+        Angle::new::<radian>(0.0),
+        AngularVelocity::new::<radian_per_second>(314.0),
+        Power::new::<megawatt>(1500.0),
+        MagneticFlux::new::<weber>(10.0),
+        Power::new::<megawatt>(1.0),
+    );
+    let mass_flow = MassRate::new::<kilogram_per_second>(1000.0);
+    let electrical_load = Power::new::<megawatt>(300.0);
+    let delta_time = Time::new::<second>(0.1);
+    let kinetic_energy = machine.signed_angular_kinetic_energy();
+    expansion.drive(&mut machine, mass_flow, electrical_load, delta_time);
+    assert!(
+        ((machine.signed_angular_kinetic_energy() - kinetic_energy)
+            - (expansion.shaft_power(mass_flow) - electrical_load) * delta_time)
+            .abs()
+            .get::<megajoule>()
+            < 1e-6
+    );
+}
+
+#[test]
+fn checked_arithmetic() {
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+    let mut water = Water::new(Mass::new::<kilogram>(1.0), temperature);
+
+    assert!(matches!(
+        water.checked_sub_mass(Mass::new::<kilogram>(2.0)),
+        Err(Error::InsufficientMass { .. })
+    ));
+    assert!(matches!(
+        water.try_remove(Mass::new::<kilogram>(-1.0)),
+        Err(Error::NegativeMass { .. })
+    ));
+    assert!(matches!(
+        water.checked_sub_volume(Volume::new::<cubic_meter>(1.0)),
+        Err(Error::VolumeExceedsAvailable { .. })
+    ));
+    assert!(matches!(
+        water.try_condensate(Mass::new::<kilogram>(0.5)),
+        Err(Error::UnexpectedPhase { expected, actual })
+            if expected == "Vapour" && actual == "Liquid"
+    ));
+    assert_eq!(water.mass(), Mass::new::<kilogram>(1.0));
+    assert!(matches!(
+        water.checked_add(Water::new_steam(Mass::new::<kilogram>(1.0), temperature)),
+        Err(Error::UnexpectedPhase { .. })
+    ));
+
+    let half = water.checked_sub_mass(Mass::new::<kilogram>(0.5)).unwrap();
+    assert_eq!(half.mass(), Mass::new::<kilogram>(0.5));
+    assert_eq!(half.temperature(), water.temperature());
+
+    let empty = Water::new(Mass::zero(), temperature);
+    assert!(matches!(
+        empty.checked_add_energy(Energy::new::<joule>(1.0)),
+        Err(Error::ZeroMassEnergyTransfer)
+    ));
+    assert!(empty.checked_add_energy(Energy::zero()).is_ok());
+
+    // Zero mass has no meaningful phase.
+    let mut empty = Water::zero();
+    let condensate = empty.try_condensate(Mass::zero()).unwrap();
+    assert!(condensate.mass().is_zero());
+    assert!(water.try_condensate(Mass::zero()).is_ok());
+    assert!(Water::new_steam(Mass::zero(), temperature)
+        .evaporate(Mass::zero())
+        .is_ok());
+}
+
+#[test]
+fn simultaneous_mass_exchange() {
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+    let mut cold = Water::new(Mass::new::<kilogram>(2.0), temperature);
+    let mut hot = Water::new(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(80.0),
+    );
+    let enthalpy = cold.enthalpy() + hot.enthalpy();
+
+    cold.try_simultaneous_mass_exchange(
+        &mut hot,
+        Mass::new::<kilogram>(1.0),
+        Mass::new::<kilogram>(0.5),
+    )
+    .unwrap();
+    assert!((cold.mass().get::<kilogram>() - 1.5).abs() < 1e-12);
+    assert!((hot.mass().get::<kilogram>() - 1.5).abs() < 1e-12);
+    assert!(((cold.enthalpy() + hot.enthalpy()) - enthalpy).abs() < Energy::new::<joule>(1e-6));
+
+    // On failure, both are left unchanged.
+    let (cold_before, hot_before) = (cold, hot);
+    assert!(matches!(
+        cold.try_simultaneous_mass_exchange(
+            &mut hot,
+            Mass::new::<kilogram>(1.0),
+            Mass::new::<kilogram>(2.0),
+        ),
+        Err(Error::InsufficientMass { .. })
+    ));
+    let mut steam = Water::new_steam(Mass::new::<kilogram>(1.0), temperature);
+    assert!(matches!(
+        cold.try_simultaneous_mass_exchange(
+            &mut steam,
+            Mass::new::<kilogram>(0.5),
+            Mass::new::<kilogram>(0.5),
+        ),
+        Err(Error::UnexpectedPhase { .. })
+    ));
+    assert_eq!(cold.mass(), cold_before.mass());
+    assert_eq!(cold.specific_enthalpy(), cold_before.specific_enthalpy());
+    assert_eq!(hot.mass(), hot_before.mass());
+    assert_eq!(steam.mass(), Mass::new::<kilogram>(1.0));
+}
+
+#[test]
+fn saturation_curve_stays_monotone() {
+    let (lowest, highest) = (
+        BOILING_POINT_BY_PRESSURE_RAW.first().unwrap().0,
+        BOILING_POINT_BY_PRESSURE_RAW.last().unwrap().0,
+    );
+    for interpolation_kind in [
+        InterpolationKind::MonotoneCubic,
+        InterpolationKind::NaturalCubicSpline,
+    ] {
+        let boiling_point_by_pressure = LinearInterpolationTable::new(
+            LimitBehaviour::Clamp,
+            BOILING_POINT_BY_PRESSURE_RAW.clone(),
+        )
+        .with_interpolation_kind(interpolation_kind);
+        let mut previous = boiling_point_by_pressure.get(lowest);
+        for i in 1..=100_000 {
+            let pressure = lowest + (highest - lowest) * i as f64 / 100_000.0;
+            let boiling_point = boiling_point_by_pressure.get(pressure);
+            assert!(
+                boiling_point >= previous,
+                "{interpolation_kind:?} at {pressure}"
+            );
+            previous = boiling_point;
+        }
+    }
+}
+
+#[test]
+fn compressed_liquid_density() {
+    // Water at 290°C and 155 bar, as in a pressurised water reactor, has 0.7459 g/cm^3.
+    let water = Water::new(
+        Mass::new::<kilogram>(1.0),
+        ThermodynamicTemperature::new::<degree_celsius>(290.0),
+    );
+    let pressure = Pressure::new::<bar>(155.0);
+    for property_backend in [PropertyBackend::Approximate, PropertyBackend::IapwsIf97] {
+        let water = water.with_property_backend(property_backend);
+        let density = water
+            .density_at_pressure(pressure)
+            .get::<kilogram_per_cubic_meter>();
+        assert!(
+            (density - 745.9).abs() < 2.0,
+            "{property_backend:?}: {density}"
+        );
+
+        // Compressing the liquid makes it denser than at saturation pressure.
+        assert!(water.density_at_pressure(pressure) > water.density());
+        assert!(
+            water.density_at_pressure(Pressure::new::<bar>(200.0))
+                > water.density_at_pressure(pressure)
+        );
+
+        // Compressing the liquid to 750 kg/m^3 takes about 167 bar.
+        let pressure = water.liquid_pressure(Volume::new::<cubic_meter>(1.0 / 750.0));
+        assert!(
+            (pressure.get::<bar>() - 167.0).abs() < 30.0,
+            "{property_backend:?}: {pressure:?}"
+        );
+
+        // The pressure starts at the saturation pressure when the liquid just fills the volume, and rises continuously.
+        assert_eq!(
+            water.liquid_pressure(water.volume()),
+            water.saturation_pressure()
+        );
+        let pressure = water.liquid_pressure(water.volume() / 1.0001);
+        assert!(
+            pressure > water.saturation_pressure()
+                && pressure < water.saturation_pressure() + Pressure::new::<bar>(5.0),
+            "{property_backend:?}: {pressure:?}"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! Transport properties of water and steam.
+//!
+//! Viscosity follows the IAPWS formulation 2008 (IAPWS R12-08),
+//! thermal conductivity the IAPWS formulation 2011 (IAPWS R15-11),
+//! and surface tension the IAPWS release on the surface tension of ordinary water substance (IAPWS R1-76(2014)).
+//! The critical enhancements of viscosity and thermal conductivity are neglected, so close to the critical point the values are too low.
+//! The density and heat capacity at a given pressure and temperature are computed by [`iapws_if97`].
+
+use typenum::{N2, P1, Z0};
+use uom::si::{
+    dynamic_viscosity::micropascal_second,
+    f64::{
+        DynamicViscosity, Force, Length, MassDensity, Pressure, Ratio, ThermalConductivity,
+        ThermodynamicTemperature,
+    },
+    force::millinewton,
+    length::meter,
+    mass_density::kilogram_per_cubic_meter,
+    thermal_conductivity::milliwatt_per_meter_kelvin,
+    thermodynamic_temperature::kelvin,
+    Quantity, ISQ, SI,
+};
+
+use crate::error::Result;
+use crate::substance::water::iapws_if97;
+
+#[cfg(test)]
+mod tests;
+
+/// Force per length, as used for surface tension.
+pub type SurfaceTension = Quantity<ISQ<Z0, P1, N2, Z0, Z0, Z0, Z0>, SI<f64>, f64>;
+
+/// Coefficients of the viscosity in the dilute-gas limit.
+const VISCOSITY_DILUTE_GAS: [f64; 4] = [1.67752, 2.20462, 0.6366564, -0.241605];
+/// Coefficients H_ij of the residual viscosity.
+const VISCOSITY_RESIDUAL: [[f64; 7]; 6] = [
+    [
+        5.20094e-1,
+        2.22531e-1,
+        -2.81378e-1,
+        1.61913e-1,
+        -3.25372e-2,
+        0.0,
+        0.0,
+    ],
+    [
+        8.50895e-2,
+        9.99115e-1,
+        -9.06851e-1,
+        2.57399e-1,
+        0.0,
+        0.0,
+        0.0,
+    ],
+    [-1.08374, 1.88797, -7.72479e-1, 0.0, 0.0, 0.0, 0.0],
+    [
+        -2.89555e-1,
+        1.26613,
+        -4.89837e-1,
+        0.0,
+        6.98452e-2,
+        0.0,
+        -4.35673e-3,
+    ],
+    [0.0, 0.0, -2.57040e-1, 0.0, 0.0, 8.72102e-3, 0.0],
+    [0.0, 1.20573e-1, 0.0, 0.0, 0.0, 0.0, -5.93264e-4],
+];
+
+/// Coefficients of the thermal conductivity in the dilute-gas limit.
+const THERMAL_CONDUCTIVITY_DILUTE_GAS: [f64; 5] = [
+    2.443221e-3,
+    1.323095e-2,
+    6.770357e-3,
+    -3.454586e-3,
+    4.096266e-4,
+];
+/// Coefficients L_ij of the residual thermal conductivity.
+const THERMAL_CONDUCTIVITY_RESIDUAL: [[f64; 6]; 5] = [
+    [
+        1.60397357,
+        -0.646013523,
+        0.111443906,
+        0.102997357,
+        -0.0504123634,
+        0.00609859258,
+    ],
+    [
+        2.33771842,
+        -2.78843778,
+        1.53616167,
+        -0.463045512,
+        0.0832827019,
+        -0.00719201245,
+    ],
+    [
+        2.19650529,
+        -4.54580785,
+        3.55777244,
+        -1.40944978,
+        0.275418278,
+        -0.0205938816,
+    ],
+    [
+        -1.21051378,
+        1.60812989,
+        -0.621178141,
+        0.0716373224,
+        0.0,
+        0.0,
+    ],
+    [
+        -2.7203370,
+        4.57586331,
+        -3.18369245,
+        1.1168348,
+        -0.19268305,
+        0.012913842,
+    ],
+];
+
+/// The dynamic viscosity of water or steam at the given pressure and temperature.
+pub fn viscosity(
+    pressure: Pressure,
+    temperature: ThermodynamicTemperature,
+) -> Result<DynamicViscosity> {
+    let properties = iapws_if97::properties(pressure, temperature)?;
+    Ok(viscosity_by_density_temperature(
+        properties.density(),
+        temperature,
+    ))
+}
+
+/// The thermal conductivity of water or steam at the given pressure and temperature.
+pub fn thermal_conductivity(
+    pressure: Pressure,
+    temperature: ThermodynamicTemperature,
+) -> Result<ThermalConductivity> {
+    let properties = iapws_if97::properties(pressure, temperature)?;
+    Ok(thermal_conductivity_by_density_temperature(
+        properties.density(),
+        temperature,
+    ))
+}
+
+/// The Prandtl number cp * mu / k of water or steam at the given pressure and temperature.
+pub fn prandtl_number(pressure: Pressure, temperature: ThermodynamicTemperature) -> Result<Ratio> {
+    let properties = iapws_if97::properties(pressure, temperature)?;
+    let viscosity = viscosity_by_density_temperature(properties.density(), temperature);
+    let thermal_conductivity =
+        thermal_conductivity_by_density_temperature(properties.density(), temperature);
+    Ok(properties.specific_isobaric_heat_capacity() * viscosity / thermal_conductivity)
+}
+
+/// The dynamic viscosity of water or steam at the given density and temperature.
+/// This can be used for saturated liquid and vapour, see [`iapws_if97::saturated_liquid`] and [`iapws_if97::saturated_vapour`].
+pub fn viscosity_by_density_temperature(
+    density: MassDensity,
+    temperature: ThermodynamicTemperature,
+) -> DynamicViscosity {
+    let (reduced_density, reduced_temperature) = reduce(density, temperature);
+
+    let dilute_gas = 100.0 * reduced_temperature.sqrt()
+        / VISCOSITY_DILUTE_GAS
+            .iter()
+            .enumerate()
+            .map(|(i, coefficient)| coefficient / reduced_temperature.powi(i as i32))
+            .sum::<f64>();
+    let residual = (reduced_density
+        * residual_sum(&VISCOSITY_RESIDUAL, reduced_density, reduced_temperature))
+    .exp();
+
+    DynamicViscosity::new::<micropascal_second>(dilute_gas * residual)
+}
+
+/// The thermal conductivity of water or steam at the given density and temperature.
+/// This can be used for saturated liquid and vapour, see [`iapws_if97::saturated_liquid`] and [`iapws_if97::saturated_vapour`].
+pub fn thermal_conductivity_by_density_temperature(
+    density: MassDensity,
+    temperature: ThermodynamicTemperature,
+) -> ThermalConductivity {
+    let (reduced_density, reduced_temperature) = reduce(density, temperature);
+
+    let dilute_gas = reduced_temperature.sqrt()
+        / THERMAL_CONDUCTIVITY_DILUTE_GAS
+            .iter()
+            .enumerate()
+            .map(|(i, coefficient)| coefficient / reduced_temperature.powi(i as i32))
+            .sum::<f64>();
+    let residual = (reduced_density
+        * residual_sum(
+            &THERMAL_CONDUCTIVITY_RESIDUAL,
+            reduced_density,
+            reduced_temperature,
+        ))
+    .exp();
+
+    ThermalConductivity::new::<milliwatt_per_meter_kelvin>(dilute_gas * residual)
+}
+
+/// The surface tension between water and steam at saturation.
+/// It vanishes at the critical temperature.
+pub fn surface_tension(temperature: ThermodynamicTemperature) -> SurfaceTension {
+    let reduced = (1.0
+        - temperature.get::<kelvin>() / iapws_if97::critical_temperature().get::<kelvin>())
+    .max(0.0);
+    let surface_tension = 235.8 * reduced.powf(1.256) * (1.0 - 0.625 * reduced);
+    Force::new::<millinewton>(surface_tension) / Length::new::<meter>(1.0)
+}
+
+/// The reduced density and temperature.
+fn reduce(density: MassDensity, temperature: ThermodynamicTemperature) -> (f64, f64) {
+    (
+        density.get::<kilogram_per_cubic_meter>()
+            / iapws_if97::critical_density().get::<kilogram_per_cubic_meter>(),
+        temperature.get::<kelvin>() / iapws_if97::critical_temperature().get::<kelvin>(),
+    )
+}
+
+/// The double sum over (1/T - 1)^i (rho - 1)^j of the residual part.
+fn residual_sum<const J: usize>(
+    coefficients: &[[f64; J]],
+    reduced_density: f64,
+    reduced_temperature: f64,
+) -> f64 {
+    let temperature_term = 1.0 / reduced_temperature - 1.0;
+    let density_term = reduced_density - 1.0;
+    coefficients
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            temperature_term.powi(i as i32)
+                * row
+                    .iter()
+                    .enumerate()
+                    .map(|(j, coefficient)| coefficient * density_term.powi(j as i32))
+                    .sum::<f64>()
+        })
+        .sum()
+}
@@ -0,0 +1,99 @@
+use uom::si::{
+    dynamic_viscosity::micropascal_second,
+    f64::{MassDensity, Pressure, ThermodynamicTemperature},
+    mass_density::kilogram_per_cubic_meter,
+    pressure::{bar, megapascal},
+    ratio::ratio,
+    thermal_conductivity::milliwatt_per_meter_kelvin,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+use super::{
+    prandtl_number, surface_tension, thermal_conductivity,
+    thermal_conductivity_by_density_temperature, viscosity, viscosity_by_density_temperature,
+};
+use crate::substance::water::iapws_if97;
+
+#[test]
+fn viscosity_reference_values() {
+    // Test values of IAPWS R12-08, table 4, which do not depend on the critical enhancement.
+    for (temperature, density, expected) in [
+        (298.15, 998.0, 889.735100),
+        (298.15, 1200.0, 1437.649467),
+        (373.15, 1000.0, 307.883622),
+        (433.15, 1.0, 14.538324),
+        (873.15, 600.0, 77.430195),
+    ] {
+        let viscosity = viscosity_by_density_temperature(
+            MassDensity::new::<kilogram_per_cubic_meter>(density),
+            ThermodynamicTemperature::new::<kelvin>(temperature),
+        )
+        .get::<micropascal_second>();
+        assert!(
+            (viscosity - expected).abs() < 1e-5,
+            "{viscosity} != {expected}"
+        );
+    }
+}
+
+#[test]
+fn thermal_conductivity_reference_values() {
+    // Test values of IAPWS R15-11, table 4, which are far from the critical point, so the critical enhancement is negligible.
+    for (temperature, density, expected) in [
+        (298.15, 0.0, 18.4341883),
+        (298.15, 998.0, 607.712868),
+        (298.15, 1200.0, 799.038144),
+        (873.15, 0.0, 79.1034659),
+    ] {
+        let thermal_conductivity = thermal_conductivity_by_density_temperature(
+            MassDensity::new::<kilogram_per_cubic_meter>(density),
+            ThermodynamicTemperature::new::<kelvin>(temperature),
+        )
+        .get::<milliwatt_per_meter_kelvin>();
+        assert!(
+            (thermal_conductivity - expected).abs() < expected * 2e-3,
+            "{thermal_conductivity} != {expected}"
+        );
+    }
+}
+
+#[test]
+fn pressurised_water() {
+    // Typical PWR primary coolant.
+    let pressure = Pressure::new::<megapascal>(15.5);
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(300.0);
+
+    let water_viscosity = viscosity(pressure, temperature).unwrap();
+    assert!((water_viscosity.get::<micropascal_second>() - 88.5).abs() < 2.0);
+    let water_thermal_conductivity = thermal_conductivity(pressure, temperature).unwrap();
+    assert!((water_thermal_conductivity.get::<milliwatt_per_meter_kelvin>() - 560.0).abs() < 10.0);
+    let water_prandtl_number = prandtl_number(pressure, temperature).unwrap();
+    assert!((water_prandtl_number.get::<ratio>() - 0.87).abs() < 0.05);
+
+    // Steam is much less viscous and conducts heat much worse.
+    let steam_temperature = ThermodynamicTemperature::new::<degree_celsius>(200.0);
+    let steam = iapws_if97::saturated_vapour(steam_temperature).unwrap();
+    assert!(
+        viscosity_by_density_temperature(steam.density(), steam_temperature)
+            < water_viscosity / 5.0
+    );
+    assert!(
+        thermal_conductivity_by_density_temperature(steam.density(), steam_temperature)
+            < water_thermal_conductivity / 5.0
+    );
+    assert!(viscosity(Pressure::new::<bar>(1.0), steam_temperature).is_ok());
+}
+
+#[test]
+fn surface_tension_reference_values() {
+    // Values of IAPWS R1-76(2014), table 1, in mN/m.
+    for (temperature, expected) in [(0.01, 75.65), (100.0, 58.91), (300.0, 14.37)] {
+        let surface_tension =
+            surface_tension(ThermodynamicTemperature::new::<degree_celsius>(temperature));
+        assert!((surface_tension.value * 1e3 - expected).abs() < 0.02);
+    }
+    assert_eq!(
+        surface_tension(iapws_if97::critical_temperature()).value,
+        0.0
+    );
+}
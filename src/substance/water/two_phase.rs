@@ -0,0 +1,324 @@
+//! Saturated water and steam flowing together, as in pipes and steam generator tubes.
+//!
+//! Unlike [`crate::container::WaterContainer`], which keeps the liquid and the vapour apart,
+//! the two phases are assumed to be in thermal equilibrium at a common pressure.
+//! The properties of both phases are computed by the flash calculations in [`flash`].
+
+use uom::num_traits::Zero;
+use uom::si::f64::{
+    AvailableEnergy, Energy, Mass, MassDensity, Pressure, Ratio, ThermodynamicTemperature, Volume,
+};
+use uom::si::ratio::ratio;
+
+use crate::error::{Error, Result};
+use crate::substance::water::chemistry::Chemistry;
+use crate::substance::water::flash::{self, State};
+use crate::substance::water::iapws_if97::Properties;
+use crate::substance::water::isotopes::IsotopeInventory;
+use crate::substance::water::{vapour_specific_enthalpy, Water};
+use crate::substance::Substance;
+
+#[cfg(test)]
+mod tests;
+
+/// A mixture of saturated water and steam, described by its pressure and quality.
+///
+/// The state is stored as specific enthalpy, like [`Water`].
+/// Below the enthalpy of saturated liquid, the water is subcooled and the quality is zero,
+/// above the enthalpy of saturated vapour, the steam is superheated and the quality is one.
+///
+/// Dissolved boron, chemicals and isotopes are carried along like in [`Water`].
+#[derive(Debug, Clone, Copy)]
+pub struct TwoPhaseWater {
+    mass: Mass,
+    pressure: Pressure,
+    specific_enthalpy: AvailableEnergy,
+    /// The state at the pressure and specific enthalpy.
+    state: State,
+    saturated_liquid: Properties,
+    saturated_vapour: Properties,
+    /// The concentration of boron by mass of the whole mixture.
+    boron_concentration: Ratio,
+    chemistry: Chemistry,
+    isotopes: IsotopeInventory,
+}
+
+impl TwoPhaseWater {
+    /// Create a saturated mixture with the given mass fraction of steam.
+    /// The pressure has to be below the critical pressure.
+    pub fn new(mass: Mass, pressure: Pressure, quality: Ratio) -> Result<Self> {
+        let quality = quality.get::<ratio>();
+        if !(0.0..=1.0).contains(&quality) {
+            return Err(Error::InvalidSteamQuality { quality });
+        }
+
+        let (saturated_liquid, saturated_vapour) = flash::saturation(pressure)?;
+        let specific_enthalpy = saturated_liquid.specific_enthalpy()
+            + (saturated_vapour.specific_enthalpy() - saturated_liquid.specific_enthalpy())
+                * quality;
+        Self::from_specific_enthalpy(mass, pressure, specific_enthalpy)
+    }
+
+    /// Create water with the given specific enthalpy, which may be subcooled, saturated or superheated.
+    pub fn from_specific_enthalpy(
+        mass: Mass,
+        pressure: Pressure,
+        specific_enthalpy: AvailableEnergy,
+    ) -> Result<Self> {
+        let (saturated_liquid, saturated_vapour) = flash::saturation(pressure)?;
+        Ok(Self {
+            mass,
+            pressure,
+            specific_enthalpy,
+            state: flash::by_pressure_enthalpy(pressure, specific_enthalpy)?,
+            saturated_liquid,
+            saturated_vapour,
+            boron_concentration: Ratio::zero(),
+            chemistry: Chemistry::zero(),
+            isotopes: IsotopeInventory::zero(),
+        })
+    }
+
+    /// Mix the liquid and the vapour of a container at the given pressure, conserving their enthalpy
+    /// and the dissolved substances.
+    pub fn from_phases(liquid: &Water, vapour: &Water, pressure: Pressure) -> Result<Self> {
+        let mass = liquid.mass() + vapour.mass();
+        if mass.is_zero() {
+            return Self::new(mass, pressure, Ratio::zero());
+        }
+        let liquid_fraction = (liquid.mass() / mass).get::<ratio>();
+        let vapour_fraction = (vapour.mass() / mass).get::<ratio>();
+        Ok(Self {
+            boron_concentration: (liquid.boron_mass() + vapour.boron_mass()) / mass,
+            chemistry: liquid
+                .chemistry
+                .mix(liquid_fraction, vapour.chemistry, vapour_fraction),
+            isotopes: liquid
+                .isotopes
+                .mix(liquid_fraction, vapour.isotopes, vapour_fraction),
+            ..Self::from_specific_enthalpy(
+                mass,
+                pressure,
+                (liquid.enthalpy() + vapour.enthalpy()) / mass,
+            )?
+        })
+    }
+
+    pub fn mass(&self) -> Mass {
+        self.mass
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn specific_enthalpy(&self) -> AvailableEnergy {
+        self.specific_enthalpy
+    }
+
+    pub fn enthalpy(&self) -> Energy {
+        self.specific_enthalpy * self.mass
+    }
+
+    /// The concentration of boron by mass, relative to the whole mixture.
+    pub fn boron_concentration(&self) -> Ratio {
+        self.boron_concentration
+    }
+
+    pub fn chemistry(&self) -> &Chemistry {
+        &self.chemistry
+    }
+
+    pub fn isotopes(&self) -> &IsotopeInventory {
+        &self.isotopes
+    }
+
+    /// The saturation temperature, or the temperature of the subcooled water or superheated steam.
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.state.temperature()
+    }
+
+    pub fn saturation_temperature(&self) -> ThermodynamicTemperature {
+        self.saturated_liquid.temperature()
+    }
+
+    /// The mass fraction of steam.
+    pub fn quality(&self) -> Ratio {
+        Ratio::new::<ratio>(self.equilibrium_quality().get::<ratio>().clamp(0.0, 1.0))
+    }
+
+    /// The equilibrium quality, which is negative for subcooled water and above one for superheated steam.
+    /// This is commonly used in heat transfer correlations.
+    pub fn equilibrium_quality(&self) -> Ratio {
+        let liquid = self.saturated_liquid.specific_enthalpy();
+        let vapour = self.saturated_vapour.specific_enthalpy();
+        (self.specific_enthalpy - liquid) / (vapour - liquid)
+    }
+
+    pub fn liquid_mass(&self) -> Mass {
+        self.mass - self.vapour_mass()
+    }
+
+    pub fn vapour_mass(&self) -> Mass {
+        self.mass * self.quality()
+    }
+
+    /// The density of the subcooled or saturated liquid.
+    pub fn liquid_density(&self) -> MassDensity {
+        if self.specific_enthalpy < self.saturated_liquid.specific_enthalpy() {
+            self.state.density()
+        } else {
+            self.saturated_liquid.density()
+        }
+    }
+
+    /// The density of the saturated or superheated vapour.
+    pub fn vapour_density(&self) -> MassDensity {
+        if self.specific_enthalpy > self.saturated_vapour.specific_enthalpy() {
+            self.state.density()
+        } else {
+            self.saturated_vapour.density()
+        }
+    }
+
+    /// The volume fraction of steam, assuming both phases flow with the same velocity.
+    pub fn void_fraction(&self) -> Ratio {
+        self.void_fraction_with_slip_ratio(Ratio::new::<ratio>(1.0))
+    }
+
+    /// The volume fraction of steam, given the ratio of the vapour velocity to the liquid velocity.
+    pub fn void_fraction_with_slip_ratio(&self, slip_ratio: Ratio) -> Ratio {
+        let quality = self.quality();
+        if quality.is_zero() {
+            return Ratio::zero();
+        }
+        let liquid_to_vapour = (Ratio::new::<ratio>(1.0) - quality) / quality
+            * (self.vapour_density() / self.liquid_density())
+            * slip_ratio;
+        Ratio::new::<ratio>(1.0) / (Ratio::new::<ratio>(1.0) + liquid_to_vapour)
+    }
+
+    /// The density of the mixture, assuming both phases flow with the same velocity.
+    pub fn density(&self) -> MassDensity {
+        let quality = self.quality().get::<ratio>();
+        1.0 / (quality / self.vapour_density() + (1.0 - quality) / self.liquid_density())
+    }
+
+    pub fn volume(&self) -> Volume {
+        self.mass / self.density()
+    }
+
+    /// Change the pressure without exchanging heat, as when the water passes a valve or an orifice.
+    /// Returns the mass of steam that flashed, which is negative if steam condensed.
+    pub fn flash(&mut self, pressure: Pressure) -> Result<Mass> {
+        let vapour_mass = self.vapour_mass();
+        *self = self.with_state(self.mass, pressure, self.specific_enthalpy)?;
+        Ok(self.vapour_mass() - vapour_mass)
+    }
+
+    /// Heat the water at constant pressure, as in a heated channel.
+    /// Returns the mass of steam that was generated, which is negative if steam condensed,
+    /// or an error if there is no water to take the energy.
+    pub fn heat(&mut self, energy: Energy) -> Result<Mass> {
+        if energy.is_zero() {
+            return Ok(Mass::zero());
+        }
+        if self.mass.is_zero() {
+            return Err(Error::ZeroMassEnergyTransfer);
+        }
+        let vapour_mass = self.vapour_mass();
+        *self = self.with_state(
+            self.mass,
+            self.pressure,
+            self.specific_enthalpy + energy / self.mass,
+        )?;
+        Ok(self.vapour_mass() - vapour_mass)
+    }
+
+    /// Mix in some other water, conserving enthalpy and the dissolved substances, and keeping the pressure of this water.
+    pub fn mix(&mut self, rhs: &Self) -> Result<()> {
+        let mass = self.mass + rhs.mass;
+        if mass.is_zero() {
+            return Ok(());
+        }
+        let self_fraction = (self.mass / mass).get::<ratio>();
+        let rhs_fraction = (rhs.mass / mass).get::<ratio>();
+        *self = Self {
+            boron_concentration: self.boron_concentration * self_fraction
+                + rhs.boron_concentration * rhs_fraction,
+            chemistry: self
+                .chemistry
+                .mix(self_fraction, rhs.chemistry, rhs_fraction),
+            isotopes: self.isotopes.mix(self_fraction, rhs.isotopes, rhs_fraction),
+            ..Self::from_specific_enthalpy(
+                mass,
+                self.pressure,
+                (self.enthalpy() + rhs.enthalpy()) / mass,
+            )?
+        };
+        Ok(())
+    }
+
+    /// Split into liquid water and steam, e.g. for a separator feeding a container.
+    ///
+    /// The liquid is saturated, or subcooled if there is no steam, and the steam takes the rest of the enthalpy,
+    /// such that the enthalpy is conserved exactly.
+    /// The boron stays in the liquid, and the dissolved gases and volatile isotopes are stripped into the steam,
+    /// as when the steam was evaporated from the liquid.
+    pub fn separate(&self) -> (Water, Water) {
+        let liquid_mass = self.liquid_mass();
+        let vapour_mass = self.vapour_mass();
+        let temperature = self.temperature();
+
+        let mut chemistry = self.chemistry;
+        let steam_chemistry = chemistry.evaporate(self.quality());
+        let mut isotopes = self.isotopes;
+        let steam_isotopes = isotopes.evaporate(self.quality());
+
+        let liquid_specific_enthalpy = if vapour_mass.is_zero() {
+            self.specific_enthalpy
+        } else {
+            self.saturated_liquid.specific_enthalpy()
+        };
+        let liquid = Water {
+            specific_enthalpy: liquid_specific_enthalpy,
+            boron_concentration: if liquid_mass.is_zero() {
+                Ratio::zero()
+            } else {
+                self.boron_concentration * (self.mass / liquid_mass)
+            },
+            chemistry,
+            isotopes,
+            ..Water::new(liquid_mass, temperature)
+        };
+
+        let steam_specific_enthalpy = if vapour_mass.is_zero() {
+            self.saturated_vapour.specific_enthalpy()
+        } else {
+            (self.enthalpy() - liquid.enthalpy()) / vapour_mass
+        };
+        let steam = Water {
+            specific_enthalpy: steam_specific_enthalpy,
+            enthalpy_offset: steam_specific_enthalpy - vapour_specific_enthalpy(temperature),
+            chemistry: steam_chemistry,
+            isotopes: steam_isotopes,
+            ..Water::new_steam(vapour_mass, temperature)
+        };
+        (liquid, steam)
+    }
+
+    /// This water with the given state, keeping the dissolved substances.
+    fn with_state(
+        &self,
+        mass: Mass,
+        pressure: Pressure,
+        specific_enthalpy: AvailableEnergy,
+    ) -> Result<Self> {
+        Ok(Self {
+            boron_concentration: self.boron_concentration,
+            chemistry: self.chemistry,
+            isotopes: self.isotopes,
+            ..Self::from_specific_enthalpy(mass, pressure, specific_enthalpy)?
+        })
+    }
+}
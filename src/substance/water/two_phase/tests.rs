@@ -0,0 +1,223 @@
+use uom::num_traits::Zero;
+use uom::si::{
+    available_energy::kilojoule_per_kilogram,
+    energy::kilojoule,
+    f64::{
+        AvailableEnergy, Energy, Mass, Pressure, Ratio, SpecificRadioactivity,
+        ThermodynamicTemperature,
+    },
+    mass::kilogram,
+    mass_density::kilogram_per_cubic_meter,
+    pressure::bar,
+    radioactivity::becquerel,
+    ratio::{part_per_million, ratio},
+    specific_radioactivity::becquerel_per_kilogram,
+    thermodynamic_temperature::degree_celsius,
+};
+
+use super::TwoPhaseWater;
+use crate::error::Error;
+use crate::substance::water::chemistry::Chemistry;
+use crate::substance::water::isotopes::{Isotope, IsotopeInventory};
+use crate::substance::water::Water;
+use crate::substance::Substance;
+
+fn assert_close(actual: f64, expected: f64, relative_tolerance: f64) {
+    assert!(
+        ((actual - expected) / expected).abs() < relative_tolerance,
+        "actual: {actual}, expected: {expected}"
+    );
+}
+
+#[test]
+fn void_fraction() {
+    let water = TwoPhaseWater::new(
+        Mass::new::<kilogram>(1.0),
+        Pressure::new::<bar>(70.0),
+        Ratio::new::<ratio>(0.1),
+    )
+    .unwrap();
+
+    // Saturated liquid has 739.7 kg/m^3 and saturated vapour 36.5 kg/m^3 at 70 bar.
+    assert_close(
+        water.saturation_temperature().get::<degree_celsius>(),
+        285.83,
+        1e-3,
+    );
+    assert_close(
+        water.liquid_density().get::<kilogram_per_cubic_meter>(),
+        739.7,
+        1e-3,
+    );
+    assert_close(
+        water.vapour_density().get::<kilogram_per_cubic_meter>(),
+        36.5,
+        1e-2,
+    );
+    assert_close(water.vapour_mass().get::<kilogram>(), 0.1, 1e-9);
+
+    // A tenth of the mass takes more than two thirds of the volume.
+    let void_fraction = water.void_fraction().get::<ratio>();
+    assert_close(void_fraction, 0.6925, 1e-2);
+    let density = water.density().get::<kilogram_per_cubic_meter>();
+    let by_void_fraction = void_fraction * water.vapour_density().get::<kilogram_per_cubic_meter>()
+        + (1.0 - void_fraction) * water.liquid_density().get::<kilogram_per_cubic_meter>();
+    assert_close(density, by_void_fraction, 1e-9);
+
+    // Steam flowing faster than the water takes less of the volume.
+    assert!(
+        water
+            .void_fraction_with_slip_ratio(Ratio::new::<ratio>(2.0))
+            .get::<ratio>()
+            < void_fraction
+    );
+
+    assert!(TwoPhaseWater::new(
+        Mass::new::<kilogram>(1.0),
+        Pressure::new::<bar>(70.0),
+        Ratio::new::<ratio>(1.1),
+    )
+    .is_err());
+    assert!(TwoPhaseWater::new(
+        Mass::new::<kilogram>(1.0),
+        Pressure::new::<bar>(250.0),
+        Ratio::new::<ratio>(0.1),
+    )
+    .is_err());
+}
+
+#[test]
+fn flash() {
+    let mut water = TwoPhaseWater::new(
+        Mass::new::<kilogram>(1.0),
+        Pressure::new::<bar>(70.0),
+        Ratio::new::<ratio>(0.0),
+    )
+    .unwrap();
+    assert_eq!(water.void_fraction().get::<ratio>(), 0.0);
+
+    // Saturated water at 70 bar has 1267.4 kJ/kg, at 1 bar the saturated liquid has 417.5 kJ/kg and the latent heat is 2257.5 kJ/kg.
+    let flashed = water.flash(Pressure::new::<bar>(1.0)).unwrap();
+    assert_close(flashed.get::<kilogram>(), 0.3765, 1e-2);
+    assert_close(water.temperature().get::<degree_celsius>(), 99.61, 1e-3);
+    assert_close(
+        water.specific_enthalpy().get::<kilojoule_per_kilogram>(),
+        1267.4,
+        1e-3,
+    );
+
+    // Compressing it again condenses all the steam, leaving subcooled water.
+    let condensed = water.flash(Pressure::new::<bar>(100.0)).unwrap();
+    assert_close(condensed.get::<kilogram>(), -0.3765, 1e-2);
+    assert!(water.equilibrium_quality().get::<ratio>() < 0.0);
+    assert!(water.temperature() < water.saturation_temperature());
+}
+
+#[test]
+fn heat() {
+    let mass = Mass::new::<kilogram>(2.0);
+    let pressure = Pressure::new::<bar>(70.0);
+    let mut water = TwoPhaseWater::from_specific_enthalpy(
+        mass,
+        pressure,
+        AvailableEnergy::new::<kilojoule_per_kilogram>(1000.0),
+    )
+    .unwrap();
+    assert_eq!(water.quality().get::<ratio>(), 0.0);
+    assert!(water.temperature() < water.saturation_temperature());
+
+    let boiled = water.heat(Energy::new::<kilojoule>(2000.0)).unwrap();
+    assert!(boiled.get::<kilogram>() > 0.0);
+    assert_close(water.enthalpy().get::<kilojoule>(), 2000.0 + 2000.0, 1e-9);
+    assert_eq!(water.temperature(), water.saturation_temperature());
+
+    // Superheated steam is hotter than saturated steam.
+    water.heat(Energy::new::<kilojoule>(6000.0)).unwrap();
+    assert_eq!(water.quality().get::<ratio>(), 1.0);
+    assert!(water.equilibrium_quality().get::<ratio>() > 1.0);
+    assert!(water.temperature() > water.saturation_temperature());
+    assert!(water.vapour_density() < water.liquid_density());
+
+    let mut empty = TwoPhaseWater::new(Mass::zero(), pressure, Ratio::zero()).unwrap();
+    assert!(matches!(
+        empty.heat(Energy::new::<kilojoule>(1.0)),
+        Err(Error::ZeroMassEnergyTransfer)
+    ));
+    assert_eq!(empty.heat(Energy::zero()).unwrap(), Mass::zero());
+
+    // Separating the phases and mixing them again keeps the mass.
+    let mut water = TwoPhaseWater::new(mass, pressure, Ratio::new::<ratio>(0.25)).unwrap();
+    let (liquid, vapour) = water.separate();
+    assert_close(liquid.mass().get::<kilogram>(), 1.5, 1e-9);
+    assert_close(vapour.mass().get::<kilogram>(), 0.5, 1e-9);
+    let mixed = TwoPhaseWater::from_phases(&liquid, &vapour, pressure).unwrap();
+    assert_close(mixed.mass().get::<kilogram>(), 2.0, 1e-9);
+
+    water.mix(&mixed).unwrap();
+    assert_close(water.mass().get::<kilogram>(), 4.0, 1e-9);
+    assert_eq!(water.pressure(), pressure);
+}
+
+#[test]
+fn separate() {
+    let pressure = Pressure::new::<bar>(70.0);
+    let temperature = ThermodynamicTemperature::new::<degree_celsius>(285.83);
+    let specific_activity = SpecificRadioactivity::new::<becquerel_per_kilogram>(1e3);
+    let liquid = Water::new(Mass::new::<kilogram>(3.0), temperature)
+        .with_boron_concentration(Ratio::new::<part_per_million>(1000.0))
+        .with_chemistry(Chemistry {
+            dissolved_oxygen: Ratio::new::<part_per_million>(1.0),
+            ..Chemistry::zero()
+        })
+        .with_isotopes(
+            IsotopeInventory::zero()
+                .with(Isotope::Xenon133, specific_activity)
+                .with(Isotope::Caesium137, specific_activity),
+        );
+    let vapour = Water::new_steam(Mass::new::<kilogram>(1.0), temperature);
+    let water = TwoPhaseWater::from_phases(&liquid, &vapour, pressure).unwrap();
+    assert_close(water.quality().get::<ratio>(), 0.25, 1e-2);
+
+    // The separated phases have the enthalpy, the mass and the dissolved substances of the mixture.
+    let (separated, steam) = water.separate();
+    assert_close(
+        (separated.enthalpy() + steam.enthalpy()).get::<kilojoule>(),
+        water.enthalpy().get::<kilojoule>(),
+        1e-12,
+    );
+    assert_close(
+        steam.mass().get::<kilogram>(),
+        water.vapour_mass().get::<kilogram>(),
+        1e-12,
+    );
+    for phase in [&separated, &steam] {
+        assert_close(
+            phase.temperature().get::<degree_celsius>(),
+            water.saturation_temperature().get::<degree_celsius>(),
+            1e-3,
+        );
+    }
+
+    // The boron stays in the liquid, and the dissolved gases are stripped into the steam.
+    assert_close(
+        separated.boron_mass().get::<kilogram>(),
+        liquid.boron_mass().get::<kilogram>(),
+        1e-9,
+    );
+    assert_eq!(steam.boron_mass(), Mass::zero());
+    let oxygen =
+        |water: &Water| (water.mass() * water.chemistry().dissolved_oxygen).get::<kilogram>();
+    assert_close(oxygen(&separated) + oxygen(&steam), oxygen(&liquid), 1e-9);
+    assert!(steam.chemistry().dissolved_oxygen > separated.chemistry().dissolved_oxygen);
+
+    for isotope in [Isotope::Xenon133, Isotope::Caesium137] {
+        let activity = |water: &Water| water.activity(isotope).get::<becquerel>();
+        assert_close(
+            activity(&separated) + activity(&steam),
+            activity(&liquid),
+            1e-9,
+        );
+    }
+    assert!(steam.activity(Isotope::Xenon133) > separated.activity(Isotope::Xenon133));
+    assert!(steam.activity(Isotope::Caesium137).is_zero());
+}
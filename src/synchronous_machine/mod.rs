@@ -17,13 +17,20 @@ use uom::{
     ConstZero,
 };
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SynchronousMachine {
+    #[cfg_attr(feature = "serde", serde(rename = "moment_of_inertia_kg_m2"))]
     moment_of_inertia: MomentOfInertia,
+    #[cfg_attr(feature = "serde", serde(rename = "angular_position_rad"))]
     angular_position: Angle,
+    #[cfg_attr(feature = "serde", serde(rename = "angular_velocity_rad_per_s"))]
     angular_velocity: AngularVelocity,
+    #[cfg_attr(feature = "serde", serde(rename = "power_rating_w"))]
     power_rating: Power,
+    #[cfg_attr(feature = "serde", serde(rename = "flux_linkage_wb"))]
     flux_linkage: MagneticFlux,
     /// The amount of power required to magnetise the rotor.
+    #[cfg_attr(feature = "serde", serde(rename = "power_requirement_w"))]
     power_requirement: Power,
 }
 